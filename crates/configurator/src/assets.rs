@@ -10,6 +10,12 @@ use rust_embed::RustEmbed;
 #[exclude = "*.DS_Store"]
 pub struct Assets;
 
+// Default .gpuiml files, copied to the config directory on the first start
+#[derive(RustEmbed)]
+#[folder = "./ui"]
+#[include = "*.gpuiml"]
+pub struct UiFiles;
+
 impl AssetSource for Assets {
     fn load(&self, path: &str) -> Result<std::borrow::Cow<'static, [u8]>> {
         println!("loading asset at path \"{}\"", path);
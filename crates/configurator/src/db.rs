@@ -1,73 +1,662 @@
-use std::sync::OnceLock;
-
-use bonsaidb::{
-    core::{
-        connection::StorageConnection,
-        document::KeyId,
-        keyvalue::{KeyStatus, KeyValue},
-        schema::Collection,
-    },
-    local::{
-        config::{Builder, StorageConfiguration},
-        vault::LocalVaultKeyStorage,
-        Database, Storage,
-    },
-};
-use serde::{de, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de, Deserialize, Serialize};
 
 use crate::paths::paths;
 
-#[derive(Clone)]
+// Configurations and app settings are in the same SQLite file
+pub fn database_path() -> PathBuf {
+    paths().data.join("config.sqlite")
+}
+
+// App settings like the theme and the window position, as JSON in the settings table. Has a
+// connection of its own, so settings can be read anywhere, not only where the ConfigDb is
 pub struct Db {
-    storage: Storage,
-    pub inner: Database,
+    conn: Mutex<Connection>,
 }
 
 pub fn db() -> &'static Db {
     static DB: OnceLock<Db> = OnceLock::new();
-    DB.get_or_init(|| Db::new())
+    DB.get_or_init(Db::new)
 }
 
 impl Db {
-    pub fn new() -> Self {
-        let data = paths().data.clone();
-        let path = data.join("bonsai/db");
-        let keys = data.join("bonsai/keys");
-        let config = StorageConfiguration::new(path)
-            .vault_key_storage(LocalVaultKeyStorage::new(keys).expect("Failed to create vault"))
-            .default_encryption_key(KeyId::Master);
-        let storage = Storage::open(config).expect("Failed to open storage");
-        storage
-            .register_schema::<()>()
-            .expect("Failed to register schema");
-        let inner = storage
-            .create_database::<()>("kv", true)
-            .expect("Failed to open database");
-
-        Self { inner, storage }
-    }
-    pub fn init_collection<'a, C: Collection + 'static>() -> Database {
-        let storage = &db().storage;
-        storage
-            .register_schema::<C>()
-            .expect("Failed to register schema");
-        let db = storage
-            .create_database::<C>(&C::collection_name().to_string(), true)
-            .expect("Failed to open database");
-        db
+    // Falls back to defaults that aren't saved when the database can't be opened
+    fn new() -> Self {
+        let path = database_path();
+        Self::open(&path).unwrap_or_else(|e| {
+            tracing::error!(
+                "Failed to open the settings database {}, settings won't be saved: {}",
+                path.display(),
+                e
+            );
+            Self::open_in_memory().expect("Failed to create an in-memory settings database")
+        })
+    }
+
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> anyhow::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
     }
+
+    fn with_connection(mut conn: Connection) -> anyhow::Result<Self> {
+        migrate(&mut conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    // None when the setting isn't saved or doesn't parse, e.g. after its type changed
     pub fn get<T: de::DeserializeOwned>(&self, id: &str) -> Option<T> {
-        if let Ok(value) = self.inner.get_key(id).into() {
-            value
-        } else {
-            None
+        let conn = self.conn.lock().unwrap();
+        let value: Option<String> = conn
+            .query_row(
+                "SELECT value FROM settings WHERE key = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()
+            .unwrap_or_else(|e| {
+                tracing::error!("Failed to read setting {}: {}", id, e);
+                None
+            });
+        serde_json::from_str(&value?).ok()
+    }
+
+    pub fn set<T: Serialize>(&self, id: &str, value: &T) -> anyhow::Result<()> {
+        let value = serde_json::to_string(value)?;
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+            params![id, value],
+        )?;
+        Ok(())
+    }
+}
+
+// Version that the user edits. Snapshots get the versions after it
+pub const WORKING_VERSION: i32 = 1;
+
+// Parameter values of one device model and configuration version
+type ConfigParams = BTreeMap<String, String>;
+
+// Configuration file for sharing a configuration, stored as JSON
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConfigExport {
+    pub device: String,
+    pub version: i32,
+    pub params: ConfigParams,
+}
+
+// Read only copy of the working version
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub version: i32,
+    pub label: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+// Schema changes, applied in order on open. The number of applied migrations is kept in the
+// user_version pragma, so new migrations are only ever appended
+const MIGRATIONS: [&str; 1] = [r#"
+    CREATE TABLE params (
+        model TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        key TEXT NOT NULL,
+        value TEXT NOT NULL,
+        PRIMARY KEY (model, version, key)
+    );
+    -- JSON values of the Db settings
+    CREATE TABLE settings (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+    -- old_value is NULL when the key wasn't set before
+    CREATE TABLE history (
+        id INTEGER PRIMARY KEY,
+        timestamp TEXT NOT NULL,
+        model TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        key TEXT NOT NULL,
+        old_value TEXT,
+        new_value TEXT NOT NULL
+    );
+    -- Number of history entries of the model and version that are applied, the ones after it
+    -- have been undone and can be redone
+    CREATE TABLE undo_state (
+        model TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        current_position INTEGER NOT NULL,
+        PRIMARY KEY (model, version)
+    );
+    CREATE TABLE snapshots (
+        model TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        label TEXT NOT NULL,
+        timestamp TEXT NOT NULL,
+        PRIMARY KEY (model, version)
+    );
+"#];
+
+#[derive(Debug, Clone)]
+struct HistoryEntry {
+    key: String,
+    old_value: Option<String>,
+    new_value: String,
+}
+
+// Device configurations in SQLite, keyed by device model, configuration version and parameter
+// key. Kept apart from the app settings, so configurations can be backed up and moved separately
+pub struct ConfigDb {
+    conn: Connection,
+}
+
+impl ConfigDb {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    // Nothing is persisted, for running without a database file
+    pub fn open_in_memory() -> anyhow::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(mut conn: Connection) -> anyhow::Result<Self> {
+        migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    pub fn get(&self, model: &str, version: i32, key: &str) -> anyhow::Result<Option<String>> {
+        get(&self.conn, model, version, key)
+    }
+
+    // Every change is recorded in the undo history
+    pub fn set(&mut self, model: &str, version: i32, key: &str, value: &str) -> anyhow::Result<()> {
+        let tx = self.conn.transaction()?;
+        set(&tx, model, version, key, value)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    // Reverts the latest edit and returns its key, None if there is nothing to undo
+    pub fn undo(&mut self, model: &str, version: i32) -> anyhow::Result<Option<String>> {
+        let tx = self.conn.transaction()?;
+        let position = position(&tx, model, version)?;
+        if position == 0 {
+            return Ok(None);
+        }
+        let Some(entry) = history_entry(&tx, model, version, position - 1)? else {
+            return Ok(None);
+        };
+        match &entry.old_value {
+            Some(old_value) => put(&tx, model, version, &entry.key, old_value)?,
+            None => {
+                tx.execute(
+                    "DELETE FROM params WHERE model = ?1 AND version = ?2 AND key = ?3",
+                    params![model, version, entry.key],
+                )?;
+            }
+        }
+        set_position(&tx, model, version, position - 1)?;
+        tx.commit()?;
+        Ok(Some(entry.key))
+    }
+
+    // Applies the latest undone edit again and returns its key, None if there is nothing to redo
+    pub fn redo(&mut self, model: &str, version: i32) -> anyhow::Result<Option<String>> {
+        let tx = self.conn.transaction()?;
+        let position = position(&tx, model, version)?;
+        let Some(entry) = history_entry(&tx, model, version, position)? else {
+            return Ok(None);
+        };
+        put(&tx, model, version, &entry.key, &entry.new_value)?;
+        set_position(&tx, model, version, position + 1)?;
+        tx.commit()?;
+        Ok(Some(entry.key))
+    }
+
+    // Sorted by key
+    pub fn list_keys(&self, model: &str, version: i32) -> anyhow::Result<Vec<String>> {
+        Ok(self.params(model, version)?.into_keys().collect())
+    }
+
+    // Copies the working version to a new version and returns the new version
+    pub fn snapshot(&mut self, model: &str, label: &str) -> anyhow::Result<i32> {
+        let tx = self.conn.transaction()?;
+        let latest: Option<i32> = tx.query_row(
+            "SELECT MAX(version) FROM snapshots WHERE model = ?1",
+            params![model],
+            |row| row.get(0),
+        )?;
+        let version = latest.unwrap_or(WORKING_VERSION) + 1;
+
+        tx.execute(
+            "INSERT INTO params (model, version, key, value)
+             SELECT model, ?3, key, value FROM params WHERE model = ?1 AND version = ?2",
+            params![model, WORKING_VERSION, version],
+        )?;
+        tx.execute(
+            "INSERT INTO snapshots (model, version, label, timestamp) VALUES (?1, ?2, ?3, ?4)",
+            params![model, version, label, Utc::now()],
+        )?;
+        tx.commit()?;
+        Ok(version)
+    }
+
+    // Oldest first
+    pub fn list_snapshots(&self, model: &str) -> anyhow::Result<Vec<Snapshot>> {
+        let mut statement = self.conn.prepare(
+            "SELECT version, label, timestamp FROM snapshots WHERE model = ?1 ORDER BY version",
+        )?;
+        let snapshots = statement
+            .query_map(params![model], |row| {
+                Ok(Snapshot {
+                    version: row.get(0)?,
+                    label: row.get(1)?,
+                    timestamp: row.get(2)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(snapshots)
+    }
+
+    pub fn export_json(&self, model: &str, version: i32) -> anyhow::Result<String> {
+        let export = ConfigExport {
+            device: model.to_string(),
+            version,
+            params: self.params(model, version)?,
+        };
+        Ok(serde_json::to_string_pretty(&export)?)
+    }
+
+    // Parameters in the JSON replace the stored ones, parameters that it doesn't have are kept.
+    // Like edits, the changes can be undone. Snapshots can't be imported over
+    pub fn import_json(&mut self, json: &str) -> anyhow::Result<()> {
+        let export: ConfigExport = serde_json::from_str(json)?;
+        let tx = self.conn.transaction()?;
+        for (key, value) in &export.params {
+            set(&tx, &export.device, export.version, key, value)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    // All values of the model and version, sorted by key
+    pub fn params(&self, model: &str, version: i32) -> anyhow::Result<ConfigParams> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT key, value FROM params WHERE model = ?1 AND version = ?2")?;
+        let params = statement
+            .query_map(params![model, version], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(params)
+    }
+}
+
+fn migrate(conn: &mut Connection) -> anyhow::Result<()> {
+    let applied: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    anyhow::ensure!(
+        applied <= MIGRATIONS.len(),
+        "config database is from a newer version of the app"
+    );
+    let tx = conn.transaction()?;
+    for migration in &MIGRATIONS[applied..] {
+        tx.execute_batch(migration)?;
+    }
+    tx.pragma_update(None, "user_version", MIGRATIONS.len())?;
+    tx.commit()?;
+    Ok(())
+}
+
+fn get(conn: &Connection, model: &str, version: i32, key: &str) -> anyhow::Result<Option<String>> {
+    Ok(conn
+        .query_row(
+            "SELECT value FROM params WHERE model = ?1 AND version = ?2 AND key = ?3",
+            params![model, version, key],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+fn put(conn: &Connection, model: &str, version: i32, key: &str, value: &str) -> anyhow::Result<()> {
+    conn.execute(
+        "INSERT INTO params (model, version, key, value) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (model, version, key) DO UPDATE SET value = excluded.value",
+        params![model, version, key, value],
+    )?;
+    Ok(())
+}
+
+fn is_snapshot(conn: &Connection, model: &str, version: i32) -> anyhow::Result<bool> {
+    Ok(conn
+        .query_row(
+            "SELECT 1 FROM snapshots WHERE model = ?1 AND version = ?2",
+            params![model, version],
+            |_| Ok(()),
+        )
+        .optional()?
+        .is_some())
+}
+
+// Changes a value and records it in the undo history
+fn set(conn: &Connection, model: &str, version: i32, key: &str, value: &str) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !is_snapshot(conn, model, version)?,
+        "{} version {} is a snapshot and can't be changed",
+        model,
+        version
+    );
+
+    let old_value = get(conn, model, version, key)?;
+    if old_value.as_deref() == Some(value) {
+        return Ok(());
+    }
+    put(conn, model, version, key, value)?;
+
+    // A new edit after undoing replaces the undone edits
+    let position = position(conn, model, version)?;
+    conn.execute(
+        "DELETE FROM history WHERE model = ?1 AND version = ?2 AND id NOT IN (
+             SELECT id FROM history WHERE model = ?1 AND version = ?2 ORDER BY id LIMIT ?3
+         )",
+        params![model, version, position],
+    )?;
+    conn.execute(
+        "INSERT INTO history (timestamp, model, version, key, old_value, new_value)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![Utc::now(), model, version, key, old_value, value],
+    )?;
+    set_position(conn, model, version, position + 1)
+}
+
+fn position(conn: &Connection, model: &str, version: i32) -> anyhow::Result<usize> {
+    Ok(conn
+        .query_row(
+            "SELECT current_position FROM undo_state WHERE model = ?1 AND version = ?2",
+            params![model, version],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0))
+}
+
+fn set_position(
+    conn: &Connection,
+    model: &str,
+    version: i32,
+    position: usize,
+) -> anyhow::Result<()> {
+    conn.execute(
+        "INSERT INTO undo_state (model, version, current_position) VALUES (?1, ?2, ?3)
+         ON CONFLICT (model, version) DO UPDATE SET current_position = excluded.current_position",
+        params![model, version, position],
+    )?;
+    Ok(())
+}
+
+// Entry at the index in the history of the model and version, oldest first
+fn history_entry(
+    conn: &Connection,
+    model: &str,
+    version: i32,
+    index: usize,
+) -> anyhow::Result<Option<HistoryEntry>> {
+    Ok(conn
+        .query_row(
+            "SELECT key, old_value, new_value FROM history WHERE model = ?1 AND version = ?2
+             ORDER BY id LIMIT 1 OFFSET ?3",
+            params![model, version, index],
+            |row| {
+                Ok(HistoryEntry {
+                    key: row.get(0)?,
+                    old_value: row.get(1)?,
+                    new_value: row.get(2)?,
+                })
+            },
+        )
+        .optional()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODEL: &str = "FMB920";
+
+    fn open() -> ConfigDb {
+        ConfigDb::open_in_memory().unwrap()
+    }
+
+    #[test]
+    fn get_set_and_list_keys() {
+        let mut db = open();
+        assert_eq!(db.get(MODEL, WORKING_VERSION, "2001").unwrap(), None);
+
+        db.set(MODEL, WORKING_VERSION, "2001", "internet").unwrap();
+        db.set(MODEL, WORKING_VERSION, "1001", "0").unwrap();
+        db.set(MODEL, WORKING_VERSION, "2001", "iot").unwrap();
+        db.set("FMB140", WORKING_VERSION, "3001", "1").unwrap();
+
+        assert_eq!(
+            db.get(MODEL, WORKING_VERSION, "2001").unwrap().as_deref(),
+            Some("iot")
+        );
+        assert_eq!(
+            db.list_keys(MODEL, WORKING_VERSION).unwrap(),
+            ["1001", "2001"]
+        );
+        assert!(db.list_keys(MODEL, WORKING_VERSION + 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn persists_to_file() {
+        let path =
+            std::env::temp_dir().join(format!("config-db-test-{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        ConfigDb::open(&path)
+            .unwrap()
+            .set(MODEL, WORKING_VERSION, "2001", "internet")
+            .unwrap();
+
+        let db = ConfigDb::open(&path).unwrap();
+        assert_eq!(
+            db.get(MODEL, WORKING_VERSION, "2001").unwrap().as_deref(),
+            Some("internet")
+        );
+        drop(db);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut db = open();
+        for value in ["1", "2", "3"] {
+            db.set(MODEL, WORKING_VERSION, "1001", value).unwrap();
         }
+
+        assert_eq!(
+            db.undo(MODEL, WORKING_VERSION).unwrap().as_deref(),
+            Some("1001")
+        );
+        assert_eq!(
+            db.undo(MODEL, WORKING_VERSION).unwrap().as_deref(),
+            Some("1001")
+        );
+        assert_eq!(
+            db.get(MODEL, WORKING_VERSION, "1001").unwrap().as_deref(),
+            Some("1")
+        );
+
+        assert_eq!(
+            db.redo(MODEL, WORKING_VERSION).unwrap().as_deref(),
+            Some("1001")
+        );
+        assert_eq!(
+            db.get(MODEL, WORKING_VERSION, "1001").unwrap().as_deref(),
+            Some("2")
+        );
+    }
+
+    #[test]
+    fn undo_first_edit_removes_key() {
+        let mut db = open();
+        db.set(MODEL, WORKING_VERSION, "1001", "1").unwrap();
+
+        assert_eq!(
+            db.undo(MODEL, WORKING_VERSION).unwrap().as_deref(),
+            Some("1001")
+        );
+        assert_eq!(db.get(MODEL, WORKING_VERSION, "1001").unwrap(), None);
+        assert_eq!(db.undo(MODEL, WORKING_VERSION).unwrap(), None);
     }
-    pub fn set<T: Serialize + Send + Sync>(
-        &self,
-        id: &str,
-        value: &T,
-    ) -> anyhow::Result<KeyStatus> {
-        Ok(self.inner.set_key(id, value).execute()?)
+
+    #[test]
+    fn edit_after_undo_drops_redo() {
+        let mut db = open();
+        db.set(MODEL, WORKING_VERSION, "1001", "1").unwrap();
+        db.set(MODEL, WORKING_VERSION, "1001", "2").unwrap();
+        db.undo(MODEL, WORKING_VERSION).unwrap();
+        db.set(MODEL, WORKING_VERSION, "1001", "3").unwrap();
+
+        assert_eq!(db.redo(MODEL, WORKING_VERSION).unwrap(), None);
+        db.undo(MODEL, WORKING_VERSION).unwrap();
+        assert_eq!(
+            db.get(MODEL, WORKING_VERSION, "1001").unwrap().as_deref(),
+            Some("1")
+        );
+    }
+
+    #[test]
+    fn snapshots_are_read_only_copies() {
+        let mut db = open();
+        db.set(MODEL, WORKING_VERSION, "2001", "internet").unwrap();
+
+        let first = db.snapshot(MODEL, "Before firmware update").unwrap();
+        db.set(MODEL, WORKING_VERSION, "2001", "iot").unwrap();
+        let second = db.snapshot(MODEL, "After firmware update").unwrap();
+        assert_eq!((first, second), (WORKING_VERSION + 1, WORKING_VERSION + 2));
+
+        let snapshots = db.list_snapshots(MODEL).unwrap();
+        let labels: Vec<_> = snapshots
+            .iter()
+            .map(|s| (s.version, s.label.as_str()))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                (first, "Before firmware update"),
+                (second, "After firmware update")
+            ]
+        );
+        assert!(db.list_snapshots("FMB140").unwrap().is_empty());
+
+        // Switching the version shows the values of that snapshot
+        assert_eq!(
+            db.get(MODEL, first, "2001").unwrap().as_deref(),
+            Some("internet")
+        );
+        assert_eq!(
+            db.get(MODEL, second, "2001").unwrap().as_deref(),
+            Some("iot")
+        );
+        assert!(db.set(MODEL, first, "2001", "iot").is_err());
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let mut db = open();
+        db.set(MODEL, WORKING_VERSION, "2001", "internet").unwrap();
+        db.set(MODEL, WORKING_VERSION, "1001", "0").unwrap();
+        let json = db.export_json(MODEL, WORKING_VERSION).unwrap();
+
+        let mut other = open();
+        other.set(MODEL, WORKING_VERSION, "3001", "1").unwrap();
+        other.import_json(&json).unwrap();
+        assert_eq!(
+            other.list_keys(MODEL, WORKING_VERSION).unwrap(),
+            ["1001", "2001", "3001"]
+        );
+        assert_eq!(
+            other
+                .get(MODEL, WORKING_VERSION, "2001")
+                .unwrap()
+                .as_deref(),
+            Some("internet")
+        );
+
+        // The import can be undone like an edit
+        other.undo(MODEL, WORKING_VERSION).unwrap();
+        other.undo(MODEL, WORKING_VERSION).unwrap();
+        assert_eq!(other.list_keys(MODEL, WORKING_VERSION).unwrap(), ["3001"]);
+    }
+
+    #[test]
+    fn import_into_snapshot_fails() {
+        let mut db = open();
+        db.set(MODEL, WORKING_VERSION, "2001", "internet").unwrap();
+        let version = db.snapshot(MODEL, "Snapshot 1").unwrap();
+        let json = db
+            .export_json(MODEL, version)
+            .unwrap()
+            .replace("internet", "iot");
+
+        assert!(db.import_json(&json).is_err());
+        assert_eq!(
+            db.get(MODEL, version, "2001").unwrap().as_deref(),
+            Some("internet")
+        );
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Position {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn settings_round_trip() {
+        let db = Db::open_in_memory().unwrap();
+        assert_eq!(db.get::<Position>("window"), None);
+
+        db.set("window", &Position { x: 10.0, y: 20.5 }).unwrap();
+        db.set("window", &Position { x: 30.0, y: 20.5 }).unwrap();
+        assert_eq!(
+            db.get::<Position>("window"),
+            Some(Position { x: 30.0, y: 20.5 })
+        );
+        // Doesn't parse as the asked type
+        assert_eq!(db.get::<String>("window"), None);
+    }
+
+    #[test]
+    fn settings_and_configurations_share_the_file() {
+        let path =
+            std::env::temp_dir().join(format!("settings-db-test-{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let settings = Db::open(&path).unwrap();
+        let mut configs = ConfigDb::open(&path).unwrap();
+
+        settings.set("theme", &"Catppuccin Mocha").unwrap();
+        configs
+            .set(MODEL, WORKING_VERSION, "2001", "internet")
+            .unwrap();
+        drop((settings, configs));
+
+        let settings = Db::open(&path).unwrap();
+        assert_eq!(
+            settings.get::<String>("theme").as_deref(),
+            Some("Catppuccin Mocha")
+        );
+        drop(settings);
+        std::fs::remove_file(&path).unwrap();
     }
 }
@@ -0,0 +1,4 @@
+// Not used until a device connection streams the raw GPS data
+#[allow(dead_code)]
+pub mod nmea;
+pub mod sms_command;
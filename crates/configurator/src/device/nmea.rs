@@ -0,0 +1,265 @@
+use anyhow::{anyhow, bail, Result};
+
+// Sentences the device streams as raw GPS data. The talker id (GP, GN, GL...) is not
+// kept because the content of e.g. GPRMC and GNRMC is the same
+#[derive(Debug, Clone, PartialEq)]
+pub enum NmeaSentence {
+    Rmc(Rmc),
+    Gga(Gga),
+}
+
+// Recommended minimum data
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rmc {
+    // UTC time as hhmmss.ss
+    pub time: String,
+    pub valid: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub speed_knots: Option<f32>,
+    pub course: Option<f32>,
+    // UTC date as ddmmyy
+    pub date: String,
+}
+
+impl Rmc {
+    // AVL records have the speed in km/h
+    pub fn speed_kmh(&self) -> Option<f32> {
+        self.speed_knots.map(|knots| knots * 1.852)
+    }
+}
+
+// Fix data
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gga {
+    // UTC time as hhmmss.ss
+    pub time: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    // 0 = no fix, 1 = GPS fix, 2 = DGPS fix...
+    pub fix_quality: u8,
+    pub satellites: u8,
+    pub hdop: Option<f32>,
+    // Meters above mean sea level
+    pub altitude: Option<f32>,
+}
+
+// Parses a single line, e.g. "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A"
+pub fn parse_sentence(line: &str) -> Result<NmeaSentence> {
+    let line = line.trim();
+    let body = line
+        .strip_prefix('$')
+        .ok_or_else(|| anyhow!("NMEA sentence must start with '$': {}", line))?;
+
+    // Checksum is optional, but must match when present
+    let body = match body.split_once('*') {
+        Some((body, checksum)) => {
+            let expected = u8::from_str_radix(checksum, 16)
+                .map_err(|_| anyhow!("Invalid NMEA checksum \"{}\"", checksum))?;
+            let actual = body.bytes().fold(0u8, |acc, b| acc ^ b);
+            if actual != expected {
+                bail!(
+                    "NMEA checksum mismatch: expected {:02X}, got {:02X}",
+                    expected,
+                    actual
+                );
+            }
+            body
+        }
+        None => body,
+    };
+
+    let fields: Vec<&str> = body.split(',').collect();
+    // Two characters of talker id and three of sentence type, e.g. GPRMC
+    let header = fields[0];
+    let kind = header
+        .get(2..)
+        .filter(|_| header.len() == 5 && header.is_ascii());
+    let Some(kind) = kind else {
+        bail!("Invalid NMEA header \"{}\"", header);
+    };
+
+    match kind {
+        "RMC" => parse_rmc(&fields),
+        "GGA" => parse_gga(&fields),
+        kind => Err(anyhow!("Unsupported NMEA sentence \"{}\"", kind)),
+    }
+}
+
+fn parse_rmc(fields: &[&str]) -> Result<NmeaSentence> {
+    if fields.len() < 10 {
+        bail!(
+            "RMC sentence has {} fields, expected at least 10",
+            fields.len()
+        );
+    }
+    Ok(NmeaSentence::Rmc(Rmc {
+        time: fields[1].to_string(),
+        valid: fields[2] == "A",
+        latitude: parse_coordinate(fields[3], fields[4], 2)?,
+        longitude: parse_coordinate(fields[5], fields[6], 3)?,
+        speed_knots: parse_optional(fields[7])?,
+        course: parse_optional(fields[8])?,
+        date: fields[9].to_string(),
+    }))
+}
+
+fn parse_gga(fields: &[&str]) -> Result<NmeaSentence> {
+    if fields.len() < 10 {
+        bail!(
+            "GGA sentence has {} fields, expected at least 10",
+            fields.len()
+        );
+    }
+    Ok(NmeaSentence::Gga(Gga {
+        time: fields[1].to_string(),
+        latitude: parse_coordinate(fields[2], fields[3], 2)?,
+        longitude: parse_coordinate(fields[4], fields[5], 3)?,
+        fix_quality: parse_optional(fields[6])?.unwrap_or(0),
+        satellites: parse_optional(fields[7])?.unwrap_or(0),
+        hdop: parse_optional(fields[8])?,
+        altitude: parse_optional(fields[9])?,
+    }))
+}
+
+// Empty fields are normal when there is no fix
+fn parse_optional<T: std::str::FromStr>(field: &str) -> Result<Option<T>> {
+    if field.is_empty() {
+        return Ok(None);
+    }
+    field
+        .parse::<T>()
+        .map(Some)
+        .map_err(|_| anyhow!("Invalid NMEA field \"{}\"", field))
+}
+
+// Converts "ddmm.mmmm" (latitude) or "dddmm.mmmm" (longitude) with a hemisphere into
+// signed decimal degrees
+fn parse_coordinate(value: &str, hemisphere: &str, degree_digits: usize) -> Result<Option<f64>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if value.len() < degree_digits || !value.is_char_boundary(degree_digits) {
+        bail!("Invalid NMEA coordinate \"{}\"", value);
+    }
+
+    let (degrees, minutes) = value.split_at(degree_digits);
+    let degrees: f64 = degrees
+        .parse()
+        .map_err(|_| anyhow!("Invalid NMEA coordinate \"{}\"", value))?;
+    let minutes: f64 = minutes
+        .parse()
+        .map_err(|_| anyhow!("Invalid NMEA coordinate \"{}\"", value))?;
+    let decimal = degrees + minutes / 60.0;
+
+    match hemisphere {
+        "N" | "E" => Ok(Some(decimal)),
+        "S" | "W" => Ok(Some(-decimal)),
+        _ => Err(anyhow!("Invalid NMEA hemisphere \"{}\"", hemisphere)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("coordinate missing");
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn parses_rmc() {
+        let sentence =
+            parse_sentence("$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A")
+                .unwrap();
+        let NmeaSentence::Rmc(rmc) = sentence else {
+            panic!("expected RMC, got {:?}", sentence);
+        };
+        assert_eq!(rmc.time, "123519");
+        assert!(rmc.valid);
+        assert_close(rmc.latitude, 48.0 + 7.038 / 60.0);
+        assert_close(rmc.longitude, 11.0 + 31.0 / 60.0);
+        assert_eq!(rmc.speed_knots, Some(22.4));
+        assert_eq!(rmc.course, Some(84.4));
+        assert_eq!(rmc.date, "230394");
+        assert!((rmc.speed_kmh().unwrap() - 41.4848).abs() < 1e-3);
+    }
+
+    #[test]
+    fn parses_gga() {
+        let sentence =
+            parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47")
+                .unwrap();
+        let NmeaSentence::Gga(gga) = sentence else {
+            panic!("expected GGA, got {:?}", sentence);
+        };
+        assert_eq!(gga.time, "123519");
+        assert_close(gga.latitude, 48.0 + 7.038 / 60.0);
+        assert_eq!(gga.fix_quality, 1);
+        assert_eq!(gga.satellites, 8);
+        assert_eq!(gga.hdop, Some(0.9));
+        assert_eq!(gga.altitude, Some(545.4));
+    }
+
+    #[test]
+    fn any_talker_id() {
+        let sentence = parse_sentence("$GNRMC,123519,V,,,,,,,230394,,").unwrap();
+        assert!(matches!(
+            sentence,
+            NmeaSentence::Rmc(Rmc { valid: false, .. })
+        ));
+    }
+
+    #[test]
+    fn southern_and_western_coordinates_are_negative() {
+        let sentence = parse_sentence("$GPRMC,123519,A,3352.128,S,15112.558,W,,,230394,,").unwrap();
+        let NmeaSentence::Rmc(rmc) = sentence else {
+            panic!("expected RMC, got {:?}", sentence);
+        };
+        assert_close(rmc.latitude, -(33.0 + 52.128 / 60.0));
+        assert_close(rmc.longitude, -(151.0 + 12.558 / 60.0));
+    }
+
+    #[test]
+    fn empty_fields_without_fix() {
+        let sentence = parse_sentence("$GPGGA,123519,,,,,0,00,,,M,,M,,").unwrap();
+        let NmeaSentence::Gga(gga) = sentence else {
+            panic!("expected GGA, got {:?}", sentence);
+        };
+        assert_eq!((gga.latitude, gga.longitude), (None, None));
+        assert_eq!((gga.fix_quality, gga.satellites), (0, 0));
+        assert_eq!((gga.hdop, gga.altitude), (None, None));
+    }
+
+    #[test]
+    fn checksum_mismatch_fails() {
+        assert!(parse_sentence(
+            "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6B"
+        )
+        .is_err());
+        assert!(parse_sentence("$GPRMC,123519,A,,,,,,,230394,,*ZZ").is_err());
+    }
+
+    #[test]
+    fn invalid_sentences_fail() {
+        for line in [
+            "GPRMC,123519,A,,,,,,,230394,,",
+            "$GPRMC,123519",
+            "$GPGSV,3,1,11",
+            "$GP,123519",
+            "$GPRMC,123519,A,48x7.038,N,,,,,230394,,",
+            "$GPRMC,123519,A,4807.038,X,,,,,230394,,",
+            // Multibyte characters must not be sliced through
+            "$Gé12,123519",
+            "$GPRMC,123519,A,4é07.038,N,,,,,230394,,",
+        ] {
+            assert!(parse_sentence(line).is_err(), "{} should fail", line);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Builds Teltonika "setparam" SMS commands, for writing the configuration of devices that
+// are only reachable over SMS.
+// Format is "<login> <password> setparam <id>:<value>;<id>:<value>". With no SMS login and
+// password set on the device, the command starts with two spaces.
+
+use anyhow::{bail, Result};
+
+// One SMS can hold 160 characters
+const SMS_MAX_LENGTH: usize = 160;
+
+#[derive(Debug, Clone, Default)]
+pub struct SmsCommandBuilder {
+    login: String,
+    password: String,
+}
+
+impl SmsCommandBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn login(mut self, login: impl Into<String>) -> Self {
+        self.login = login.into();
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = password.into();
+        self
+    }
+
+    pub fn build_set_param(&self, key: &str, value: &str) -> String {
+        format!("{}{}:{}", self.prefix(), key, value)
+    }
+
+    // Packs as many parameters into one SMS as fit, and starts a new one when they don't. Fails
+    // if a parameter doesn't fit into an SMS even on its own
+    pub fn build_set_params<'a>(
+        &self,
+        params: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Vec<String>> {
+        let prefix = self.prefix();
+        let mut commands = Vec::new();
+        let mut current = String::new();
+
+        for (key, value) in params {
+            let single = self.build_set_param(key, value);
+            if single.len() > SMS_MAX_LENGTH {
+                bail!(
+                    "Parameter {} is too long for an SMS, the command has {} characters",
+                    key,
+                    single.len()
+                );
+            }
+            let param = format!("{}:{}", key, value);
+            if !current.is_empty()
+                && prefix.len() + current.len() + 1 + param.len() > SMS_MAX_LENGTH
+            {
+                commands.push(format!("{}{}", prefix, current));
+                current.clear();
+            }
+            if !current.is_empty() {
+                current.push(';');
+            }
+            current.push_str(&param);
+        }
+
+        if !current.is_empty() {
+            commands.push(format!("{}{}", prefix, current));
+        }
+        Ok(commands)
+    }
+
+    fn prefix(&self) -> String {
+        format!("{} {} setparam ", self.login, self.password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_param_without_login() {
+        let builder = SmsCommandBuilder::new();
+        assert_eq!(
+            builder.build_set_param("2001", "internet"),
+            "  setparam 2001:internet"
+        );
+    }
+
+    #[test]
+    fn set_param_with_login() {
+        let builder = SmsCommandBuilder::new().login("user").password("secret");
+        assert_eq!(
+            builder
+                .build_set_params([("2001", "internet"), ("2002", "user")])
+                .unwrap(),
+            ["user secret setparam 2001:internet;2002:user"]
+        );
+    }
+
+    #[test]
+    fn params_are_split_at_160_characters() {
+        let values: Vec<(String, String)> = (0..30)
+            .map(|i| (format!("{}", 10000 + i), "x".repeat(10)))
+            .collect();
+        let params = values.iter().map(|(k, v)| (k.as_str(), v.as_str()));
+        let commands = SmsCommandBuilder::new().build_set_params(params).unwrap();
+
+        assert!(commands.len() > 1);
+        for command in &commands {
+            assert!(command.len() <= SMS_MAX_LENGTH, "{} is too long", command);
+            assert!(command.starts_with("  setparam "));
+        }
+        // Every command but the last is full, the next parameter wouldn't have fit
+        for command in &commands[..commands.len() - 1] {
+            assert!(command.len() + ";10000:xxxxxxxxxx".len() > SMS_MAX_LENGTH);
+        }
+        // All parameters are sent, in order
+        let sent: Vec<String> = commands
+            .iter()
+            .flat_map(|command| command["  setparam ".len()..].split(';'))
+            .map(str::to_string)
+            .collect();
+        let expected: Vec<String> = values.iter().map(|(k, v)| format!("{}:{}", k, v)).collect();
+        assert_eq!(sent, expected);
+    }
+
+    #[test]
+    fn exactly_160_characters_fit() {
+        let prefix = "  setparam 2001:".len();
+        let value = "x".repeat(SMS_MAX_LENGTH - prefix);
+        let commands = SmsCommandBuilder::new()
+            .build_set_params([("2001", value.as_str())])
+            .unwrap();
+        assert_eq!(commands[0].len(), SMS_MAX_LENGTH);
+    }
+
+    #[test]
+    fn too_long_param_fails() {
+        let value = "x".repeat(SMS_MAX_LENGTH);
+        assert!(SmsCommandBuilder::new()
+            .build_set_params([("2001", "internet"), ("2002", value.as_str())])
+            .is_err());
+    }
+
+    #[test]
+    fn no_params_no_commands() {
+        let commands = SmsCommandBuilder::new().build_set_params([]).unwrap();
+        assert!(commands.is_empty());
+    }
+}
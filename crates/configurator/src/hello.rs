@@ -5,12 +5,37 @@ use futures::{
     SinkExt, StreamExt,
 };
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use std::{borrow::Cow, io::Read};
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::{
     fs::File,
     sync::{Arc, Mutex},
 };
 
+use crate::db::{database_path, ConfigDb, ConfigExport, WORKING_VERSION};
+use crate::device::sms_command::SmsCommandBuilder;
+use crate::paths::paths;
+use crate::theme::Theme;
+use crate::window_startup::WindowStartupBehavior;
+use xml2gpui::components::input::select::{InputSelect, InputSelectEvent, SelectOption};
+
+actions!(
+    configurator,
+    [
+        Reload,
+        OpenFile,
+        ExportConfig,
+        ImportConfig,
+        Undo,
+        Redo,
+        SaveSnapshot,
+        CopySmsCommands
+    ]
+);
+
 pub enum FileChangeEvent {
     DataChange,
 }
@@ -19,17 +44,132 @@ impl EventEmitter<FileChangeEvent> for HelloWorld {}
 pub struct HelloWorld {
     pub text: SharedString,
     pub root_component: xml2gpui::tree::Component,
+    pub view_cache: xml2gpui::tree::ViewCache,
+    pub handlers: xml2gpui::handlers::HandlerRegistry,
+    pub bindings: xml2gpui::bindings::BindingRegistry,
+    pub suggestions: xml2gpui::suggestions::SuggestionsRegistry,
+    // Values for {key} placeholders in the XML texts
+    pub context: xml2gpui::context::RenderContext,
+    // Root UI file. Files it <include>s are loaded relative to it
+    pub ui_path: PathBuf,
+    // Colors of the semantic classes like bg-primary, hot-reloaded like the UI files
+    pub theme_path: PathBuf,
+    // Last parse error of the UI files, shown over the last good UI until the files are fixed
+    pub parse_error: Option<xml2gpui::tree::ParseError>,
+    focus_handle: FocusHandle,
+    watcher: RecommendedWatcher,
+    // Directories of the loaded UI files. Directories are watched instead of the files, because
+    // editors often save by replacing the file
+    watched_dirs: HashSet<PathBuf>,
+    // Content hash of every loaded UI file, None if it couldn't be read
+    file_hashes: HashMap<PathBuf, Option<u64>>,
+    // Bound values are stored under this device model and configuration version
+    pub device_model: String,
+    pub config_version: i32,
+    config_db: ConfigDb,
+    // Values the bound models had when they were bound, e.g. from the XML. Shown when the
+    // database has no value for the key, like after undoing its first edit
+    bound_defaults: HashMap<String, String>,
+    // Switches between the working version and the snapshots
+    snapshot_picker: View<InputSelect>,
+    // Display the window opens on at the next start
+    display_picker: View<InputSelect>,
+    // Last title set from the root element's title attribute
+    window_title: Option<String>,
 }
 
 impl HelloWorld {
     pub fn new(cx: &mut WindowContext) -> View<Self> {
-        let xml = HelloWorld::read_xml_file();
-        let this = Self {
-            text: "Hello, World!".into(),
-            root_component: xml2gpui::tree::parse_xml(xml),
-        };
+        let ui_path = paths().ui_dir().join("FMBFAMILY.gpuiml");
+        cx.bind_keys([
+            KeyBinding::new("ctrl-z", Undo, None),
+            KeyBinding::new("ctrl-y", Redo, None),
+            KeyBinding::new("cmd-z", Undo, None),
+            KeyBinding::new("cmd-shift-z", Redo, None),
+        ]);
+
+        let mut handlers = xml2gpui::handlers::HandlerRegistry::default();
+        register_default_handlers(&mut handlers);
+
+        let (watcher, mut rx) = async_watcher().unwrap();
+        // E.g. another instance has the database locked. The app still works, edits just aren't
+        // saved
+        let config_db_path = database_path();
+        let config_db = ConfigDb::open(&config_db_path).unwrap_or_else(|e| {
+            tracing::error!(
+                "Failed to open the config database {}, changes won't be saved: {}",
+                config_db_path.display(),
+                e
+            );
+            ConfigDb::open_in_memory().expect("Failed to create an in-memory config database")
+        });
 
-        let view = cx.new_view(|_cx| this);
+        let view = cx.new_view(|cx| {
+            // Focused root receives the actions dispatched by the default handlers
+            let focus_handle = cx.focus_handle();
+            cx.focus(&focus_handle);
+
+            let snapshot_picker =
+                cx.new_view(|cx| InputSelect::new(cx).selected(WORKING_VERSION.to_string()));
+            cx.subscribe(&snapshot_picker, |this, _, event, cx| match event {
+                InputSelectEvent::Selected(value) => match value.parse() {
+                    Ok(version) => this.show_version(version, cx),
+                    Err(e) => tracing::error!("Invalid snapshot version {}: {}", value, e),
+                },
+            })
+            .detach();
+
+            let theme_path = ui_path.with_file_name("theme.toml");
+            HelloWorld::load_theme(&theme_path, cx);
+            // Stateful views have the theme colors baked in, so they are rebuilt when the theme
+            // is reloaded or switched between light and dark
+            cx.observe_global::<xml2gpui::theme::Theme>(|this, cx| {
+                this.view_cache.clear();
+                cx.notify();
+            })
+            .detach();
+
+            let mut parse_context = xml2gpui::tree::ParseContext::default();
+            // Nothing good to show yet, so the error is also the UI
+            let (root_component, parse_error) =
+                match HelloWorld::read_xml_file(&ui_path, &mut parse_context) {
+                    Ok(component) => (component, None),
+                    Err(e) => (
+                        xml2gpui::tree::Component::error(format!(
+                            "Error in {}: {}",
+                            ui_path.display(),
+                            e
+                        )),
+                        Some(e),
+                    ),
+                };
+            let mut this = Self {
+                text: "Hello, World!".into(),
+                root_component,
+                view_cache: xml2gpui::tree::ViewCache::default(),
+                handlers,
+                bindings: xml2gpui::bindings::BindingRegistry::default(),
+                suggestions: xml2gpui::suggestions::SuggestionsRegistry::default(),
+                context: HelloWorld::default_context(),
+                ui_path,
+                theme_path,
+                parse_error,
+                focus_handle,
+                watcher,
+                watched_dirs: HashSet::new(),
+                file_hashes: HashMap::new(),
+                device_model: "FMBFAMILY".to_string(),
+                config_version: WORKING_VERSION,
+                config_db,
+                bound_defaults: HashMap::new(),
+                snapshot_picker,
+                display_picker: WindowStartupBehavior::display_picker(cx),
+                window_title: None,
+            };
+            this.watch_ui_files(&parse_context);
+            this.update_snapshot_picker(cx);
+            this
+        });
 
         // Listen for file change events. Now file change are triggered on this view, but later
         // we can move the file listener to somewhere else
@@ -37,43 +177,30 @@ impl HelloWorld {
             &view,
             |subscriber, emitter: &FileChangeEvent, cx| match emitter {
                 FileChangeEvent::DataChange => {
-                    subscriber.update(cx, |this, cx| {
-                        this.root_component =
-                            xml2gpui::tree::parse_xml(HelloWorld::read_xml_file());
-                        cx.notify();
-                    });
+                    subscriber.update(cx, |this, cx| this.reload(cx));
                 }
                 _ => {}
             },
         )
         .detach();
 
-        // First we start the file watcher
+        // Then we listen to the watcher. The watched files are updated on every reload
         let view_clone = view.clone();
         cx.spawn(|mut cx| async move {
-            let (mut watcher, mut rx) = async_watcher().unwrap();
-
-            // Add a path to be watched. All files and directories at that path and
-            // below will be monitored for changes.
-            watcher
-                .watch(
-                    std::path::Path::new("crates/configurator/ui"),
-                    RecursiveMode::Recursive,
-                )
-                .unwrap();
-
             while let Some(res) = rx.next().await {
                 match res {
+                    // Saving by replacing the file shows up as a create or a rename, the content
+                    // hash check filters out the events that didn't change anything
                     Ok(event) => match event.kind {
-                        EventKind::Modify(modify_kind) => match modify_kind {
-                            notify::event::ModifyKind::Data(_) => {
-                                cx.update_view(&view_clone, |this, cx| {
+                        EventKind::Modify(_) | EventKind::Create(_) => {
+                            cx.update_view(&view_clone, |this, cx| {
+                                if this.loaded_file_changed(&event.paths) {
                                     cx.emit(FileChangeEvent::DataChange);
                                     cx.notify();
-                                });
-                            }
-                            _ => {}
-                        },
+                                }
+                            })
+                            .ok();
+                        }
                         _ => {}
                     },
                     Err(e) => println!("watch error: {:?}", e),
@@ -85,40 +212,579 @@ impl HelloWorld {
         view
     }
 
-    pub fn read_xml_file() -> String {
-        // First load file FMT100.gpuiml from "ui" directory directly to string
-        let mut xml = String::new();
-        std::fs::File::open("crates/configurator/ui/FMBFAMILY.gpuiml")
-            .unwrap()
-            .read_to_string(&mut xml)
-            .unwrap();
+    pub fn read_xml_file(
+        path: &Path,
+        parse_context: &mut xml2gpui::tree::ParseContext,
+    ) -> Result<xml2gpui::tree::Component, xml2gpui::tree::ParseError> {
+        match xml2gpui::tree::parse_file_with_context(path, parse_context) {
+            Ok(component) => {
+                // Typos in class names etc. would otherwise be silently ignored
+                for error in xml2gpui::validator::ComponentTreeValidator::validate(&component) {
+                    tracing::warn!("{}: {}", path.display(), error);
+                }
+                Ok(component)
+            }
+            Err(e) => {
+                tracing::error!("Failed to parse {}: {}", path.display(), e);
+                Err(e)
+            }
+        }
+    }
+
+    pub fn reload(&mut self, cx: &mut ViewContext<Self>) {
+        let mut parse_context = xml2gpui::tree::ParseContext::default();
+        let result = HelloWorld::read_xml_file(&self.ui_path, &mut parse_context);
+        // Includes may have been added or removed
+        self.watch_ui_files(&parse_context);
+        HelloWorld::load_theme(&self.theme_path, cx);
+
+        // A typo in the XML keeps the last good UI on screen, with the error over it
+        let new_root = match result {
+            Ok(new_root) => new_root,
+            Err(e) => {
+                self.parse_error = Some(e);
+                cx.notify();
+                return;
+            }
+        };
+        if self.parse_error.take().is_some() {
+            cx.notify();
+        }
+
+        let changes = xml2gpui::diff::ComponentDiff::compute(&self.root_component, &new_root);
+        // Saving without changes (or touching an unrelated file) doesn't re-render
+        if changes.is_empty() {
+            return;
+        }
+
+        xml2gpui::diff::ComponentDiff::apply(&changes, &mut self.root_component);
+        // Stateful views were built from the old XML, and the component numbers
+        // they are cached by may have moved
+        self.view_cache.clear();
+        cx.notify();
+    }
+
+    // Title attribute of the root element, with the {key} placeholders filled in. Checked on every
+    // render, so reloads and context changes both update it
+    fn update_window_title(&mut self, cx: &mut WindowContext) {
+        let title = self
+            .root_component
+            .attributes
+            .iter()
+            .find(|(k, _)| k == "title")
+            .map(|(_, title)| self.context.interpolate(title));
+        if title == self.window_title {
+            return;
+        }
+        // Without a title attribute the last one stays, GPUI can't reset to the OS default
+        if let Some(title) = &title {
+            cx.set_window_title(title);
+        }
+        self.window_title = title;
+    }
+
+    // Without a theme.toml the UI files get the default theme
+    fn load_theme(path: &Path, cx: &mut AppContext) {
+        let mut theme = match xml2gpui::theme::Theme::load(path) {
+            Ok(theme) => theme,
+            Err(xml2gpui::theme::ThemeError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                xml2gpui::theme::Theme::default()
+            }
+            // Keep the previous theme until the file is fixed
+            Err(e) => {
+                tracing::error!("Failed to load {}: {}", path.display(), e);
+                return;
+            }
+        };
+        // Light or dark mode isn't in the file, it stays as it was
+        let current = xml2gpui::theme::Theme::current(cx);
+        theme.mode = current.mode;
+        if theme != current {
+            cx.set_global(theme);
+        }
+    }
+
+    // Loaded UI files and the theme
+    fn watch_ui_files(&mut self, parse_context: &xml2gpui::tree::ParseContext) {
+        // Loaded files are stored canonicalized, the theme has to match them
+        let theme_path = self
+            .theme_path
+            .canonicalize()
+            .unwrap_or_else(|_| self.theme_path.clone());
+        self.watch_files(parse_context.loaded_files().chain([theme_path.as_path()]));
+    }
+
+    fn watch_files<'a>(&mut self, files: impl Iterator<Item = &'a Path>) {
+        self.file_hashes = files
+            .map(|path| (path.to_path_buf(), content_hash(path)))
+            .collect();
+
+        let dirs: HashSet<PathBuf> = self
+            .file_hashes
+            .keys()
+            .filter_map(|path| path.parent().map(Path::to_path_buf))
+            .collect();
+        for dir in self.watched_dirs.difference(&dirs) {
+            self.watcher.unwatch(dir).ok();
+        }
+        for dir in dirs.difference(&self.watched_dirs) {
+            if let Err(e) = self.watcher.watch(dir, RecursiveMode::NonRecursive) {
+                tracing::warn!("Can't watch {}: {}", dir.display(), e);
+            }
+        }
+        self.watched_dirs = dirs;
+    }
+
+    // True if any of the paths is a loaded UI file whose content is different than last time
+    fn loaded_file_changed(&mut self, paths: &[PathBuf]) -> bool {
+        let mut changed = false;
+        for path in paths {
+            // Loaded files are stored canonicalized by the parser
+            let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+            if let Some(hash) = self.file_hashes.get_mut(&path) {
+                let new_hash = content_hash(&path);
+                if *hash != new_hash {
+                    *hash = new_hash;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    fn load_bound_value(&self, key: &str, model: &Model<String>, cx: &mut ViewContext<Self>) {
+        let stored = match self
+            .config_db
+            .get(&self.device_model, self.config_version, key)
+        {
+            Ok(stored) => stored,
+            Err(e) => {
+                tracing::error!("Failed to read {} from the config database: {}", key, e);
+                return;
+            }
+        };
+        let Some(new_value) = stored.or_else(|| self.bound_defaults.get(key).cloned()) else {
+            return;
+        };
+        if *model.read(cx) != new_value {
+            model.update(cx, |value, cx| {
+                *value = new_value;
+                cx.notify();
+            });
+        }
+    }
+
+    fn load_bound_values(&self, cx: &mut ViewContext<Self>) {
+        for (key, model) in self.bindings.iter() {
+            self.load_bound_value(key, model, cx);
+        }
+    }
+
+    fn undo(&mut self, cx: &mut ViewContext<Self>) {
+        match self.config_db.undo(&self.device_model, self.config_version) {
+            Ok(Some(_)) => self.load_bound_values(cx),
+            Ok(None) => (),
+            Err(e) => tracing::error!("Failed to undo: {}", e),
+        }
+    }
+
+    fn redo(&mut self, cx: &mut ViewContext<Self>) {
+        match self.config_db.redo(&self.device_model, self.config_version) {
+            Ok(Some(_)) => self.load_bound_values(cx),
+            Ok(None) => (),
+            Err(e) => tracing::error!("Failed to redo: {}", e),
+        }
+    }
+
+    fn save_snapshot(&mut self, cx: &mut ViewContext<Self>) {
+        let label = match self.config_db.list_snapshots(&self.device_model) {
+            Ok(snapshots) => format!("Snapshot {}", snapshots.len() + 1),
+            Err(e) => {
+                tracing::error!("Failed to read the snapshots: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = self.config_db.snapshot(&self.device_model, &label) {
+            tracing::error!("Failed to save a snapshot: {}", e);
+            return;
+        }
+        self.update_snapshot_picker(cx);
+    }
+
+    fn update_snapshot_picker(&mut self, cx: &mut ViewContext<Self>) {
+        let snapshots = match self.config_db.list_snapshots(&self.device_model) {
+            Ok(snapshots) => snapshots,
+            Err(e) => {
+                tracing::error!("Failed to read the snapshots: {}", e);
+                Vec::new()
+            }
+        };
+
+        let mut options = vec![SelectOption::new(WORKING_VERSION.to_string(), "Current")];
+        options.extend(snapshots.iter().rev().map(|snapshot| {
+            SelectOption::new(
+                snapshot.version.to_string(),
+                format!(
+                    "{} ({})",
+                    snapshot.label,
+                    snapshot.timestamp.format("%Y-%m-%d %H:%M")
+                ),
+            )
+        }));
+        self.snapshot_picker
+            .update(cx, |picker, cx| picker.set_options(options, cx));
+    }
+
+    // Shows the values of the working version or a snapshot in the bound inputs
+    fn show_version(&mut self, version: i32, cx: &mut ViewContext<Self>) {
+        self.config_version = version;
+        self.load_bound_values(cx);
+        cx.notify();
+    }
+
+    // Writes the current configuration to a JSON file the user picks
+    fn export_config(&mut self, cx: &mut ViewContext<Self>) {
+        let json = match self
+            .config_db
+            .export_json(&self.device_model, self.config_version)
+        {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::error!("Failed to export the configuration: {}", e);
+                return;
+            }
+        };
+
+        let path = cx.prompt_for_new_path(&paths().config);
+        cx.spawn(|_, _| async move {
+            if let Ok(Some(path)) = path.await {
+                if let Err(e) = std::fs::write(&path, json) {
+                    tracing::error!("Failed to write {}: {}", path.display(), e);
+                }
+            }
+        })
+        .detach();
+    }
+
+    // Copies setparam SMS commands for the current configuration, for devices that are only
+    // reachable over SMS. The SMS login and password (3003 and 3004) authenticate the commands,
+    // so they aren't changed with them
+    fn copy_sms_commands(&mut self, cx: &mut ViewContext<Self>) {
+        let mut params = match self
+            .config_db
+            .params(&self.device_model, self.config_version)
+        {
+            Ok(params) => params,
+            Err(e) => {
+                tracing::error!("Failed to read the configuration: {}", e);
+                return;
+            }
+        };
+        let builder = SmsCommandBuilder::new()
+            .login(params.remove("3003").unwrap_or_default())
+            .password(params.remove("3004").unwrap_or_default());
+        match builder.build_set_params(params.iter().map(|(k, v)| (k.as_str(), v.as_str()))) {
+            Ok(commands) => cx.write_to_clipboard(ClipboardItem::new(commands.join("\n"))),
+            Err(e) => tracing::error!("Failed to build the SMS commands: {}", e),
+        }
+    }
+
+    // Reads a configuration exported with export_config. Asks before overwriting a configuration
+    // that is already in the database
+    fn import_config(&mut self, cx: &mut ViewContext<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+        });
+        cx.spawn(|this, mut cx| async move {
+            let Ok(Some(paths)) = paths.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let export = std::fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|json| Ok((serde_json::from_str::<ConfigExport>(&json)?, json)));
+            let (export, json) = match export {
+                Ok(export) => export,
+                Err(e) => {
+                    tracing::error!("Failed to read {}: {}", path.display(), e);
+                    return;
+                }
+            };
+
+            let overwrite = this.update(&mut cx, |this, cx| {
+                let exists = this
+                    .config_db
+                    .list_keys(&export.device, export.version)
+                    .is_ok_and(|keys| !keys.is_empty());
+                exists.then(|| {
+                    cx.prompt(
+                        PromptLevel::Warning,
+                        &format!(
+                            "Overwrite the {} configuration version {}?",
+                            export.device, export.version
+                        ),
+                        None,
+                        &["Overwrite", "Cancel"],
+                    )
+                })
+            });
+            // Err when the view is gone
+            let Ok(answer) = overwrite else {
+                return;
+            };
+            if let Some(answer) = answer {
+                if answer.await != Ok(0) {
+                    return;
+                }
+            }
+
+            this.update(&mut cx, |this, cx| {
+                if let Err(e) = this.config_db.import_json(&json) {
+                    tracing::error!("Failed to import {}: {}", path.display(), e);
+                    return;
+                }
+                // Inputs show the imported values
+                this.load_bound_values(cx);
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    // Asks for a .gpuiml file and shows it instead of the current one
+    fn open_file(&mut self, cx: &mut ViewContext<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+        });
+        cx.spawn(|this, mut cx| async move {
+            let Ok(Some(paths)) = paths.await else {
+                return;
+            };
+            if let Some(path) = paths.into_iter().next() {
+                this.update(&mut cx, |this, cx| {
+                    this.ui_path = path;
+                    this.reload(cx);
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    // Handlers are looked up by name from the on:click etc. attributes in the XML
+    pub fn register<F: Fn(&mut WindowContext) + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        handler: F,
+    ) {
+        self.handlers.register_handler(name, handler);
+    }
+
+    // Suggestion lists for <input type="autocomplete" suggestions="name">
+    pub fn register_suggestions<F: Fn(&str) -> Vec<String> + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        provider: F,
+    ) {
+        self.suggestions.register(name, provider);
+    }
+
+    // Device info is added here once a device is connected
+    fn default_context() -> xml2gpui::context::RenderContext {
+        let mut context = xml2gpui::context::RenderContext::default();
+        context.set("app_version", env!("CARGO_PKG_VERSION"));
+        context
+    }
+
+    pub fn set_context_value(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.context.set(key, value);
+    }
+
+    // Inputs with bind="key" in the XML read and write this model
+    // Bound values are persisted to the config database, and the stored value replaces the
+    // model's current one
+    pub fn bind(
+        &mut self,
+        key: impl Into<String>,
+        model: Model<String>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let key = key.into();
+        self.bound_defaults
+            .entry(key.clone())
+            .or_insert_with(|| model.read(cx).clone());
+        self.load_bound_value(&key, &model, cx);
+
+        cx.observe(&model, {
+            let key = key.clone();
+            move |this, model, cx| {
+                // Snapshots are read only
+                if this.config_version != WORKING_VERSION {
+                    return;
+                }
+                let value = model.read(cx).clone();
+                // Showing the default of a key that isn't stored, e.g. after an undo, isn't an edit
+                let stored = this
+                    .config_db
+                    .get(&this.device_model, this.config_version, &key);
+                if matches!(stored, Ok(None)) && this.bound_defaults.get(&key) == Some(&value) {
+                    return;
+                }
+                if let Err(e) =
+                    this.config_db
+                        .set(&this.device_model, this.config_version, &key, &value)
+                {
+                    tracing::error!("Failed to save {} to the config database: {}", key, e);
+                }
+            }
+        })
+        .detach();
 
-        xml
+        self.bindings.bind(key, model);
+        // Inputs that were built before the model was registered aren't bound yet
+        self.view_cache.clear();
     }
 }
 
 impl Render for HelloWorld {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        // // Time the render
-        // let start = std::time::Instant::now();
+        self.update_window_title(cx);
 
-        // Pass a reference to the locked component to render_component
-        let components = xml2gpui::tree::render_component(&self.root_component);
+        // Dev mode times every component of the frame
+        if xml2gpui::dev_mode::DevMode::enabled(cx) {
+            xml2gpui::dev_mode::RenderProfile::reset(cx);
+        }
 
-        // // Print the render time
-        // let elapsed = start.elapsed();
-        // println!("Component construction time: {:?}", elapsed);
+        // Render through an error boundary, so a failing element shows an error instead of crashing
+        let components = xml2gpui::error_boundary::ErrorBoundary::new(
+            &self.root_component,
+            "Error: could not render the UI",
+        )
+        .render(
+            &mut self.view_cache,
+            &self.handlers,
+            &self.bindings,
+            &self.suggestions,
+            &self.context,
+            cx,
+        );
 
         // Root element must be a div
-        match components {
+        let root = match components {
             xml2gpui::tree::ComponentType::Div(div) => div,
             _ => div()
                 .id("error")
                 .child("Error: root element must be a div!"),
-        }
+        };
+
+        div()
+            .id("hello-world")
+            .relative()
+            .size_full()
+            .flex()
+            .flex_col()
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(|this, _: &Reload, cx| this.reload(cx)))
+            .on_action(cx.listener(|this, _: &OpenFile, cx| this.open_file(cx)))
+            .on_action(cx.listener(|this, _: &ExportConfig, cx| this.export_config(cx)))
+            .on_action(cx.listener(|this, _: &ImportConfig, cx| this.import_config(cx)))
+            .on_action(cx.listener(|this, _: &Undo, cx| this.undo(cx)))
+            .on_action(cx.listener(|this, _: &Redo, cx| this.redo(cx)))
+            .on_action(cx.listener(|this, _: &SaveSnapshot, cx| this.save_snapshot(cx)))
+            .on_action(cx.listener(|this, _: &CopySmsCommands, cx| this.copy_sms_commands(cx)))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .px_2()
+                    .child("Configuration")
+                    .child(self.snapshot_picker.clone())
+                    .child("Display")
+                    .child(self.display_picker.clone()),
+            )
+            .child(div().flex_1().overflow_hidden().child(root))
+            .children(
+                self.parse_error
+                    .as_ref()
+                    .map(|error| parse_error_overlay(error, cx)),
+            )
     }
 }
 
+// Bottom half of the window, over the UI
+fn parse_error_overlay(error: &xml2gpui::tree::ParseError, cx: &WindowContext) -> Div {
+    let font_mono = cx.global::<Theme>().font_mono.clone();
+    let mut overlay = div()
+        .absolute()
+        .bottom_0()
+        .left_0()
+        .w_full()
+        .h_1_2()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .p_4()
+        .bg(rgba(0x7f1d1de6))
+        .text_color(rgb(0xffffff))
+        .child(
+            div()
+                .font_weight(FontWeight::BOLD)
+                .child("Failed to parse the UI file"),
+        )
+        .child(div().font(font_mono.clone()).child(error.to_string()));
+
+    if let xml2gpui::tree::ParseError::Located { snippet, .. } = error {
+        overlay = overlay.child(
+            div()
+                .font(font_mono)
+                .p_2()
+                .rounded_md()
+                .bg(rgba(0x00000066))
+                .children(snippet.lines().map(|line| line.to_string())),
+        );
+    }
+    overlay
+}
+
+// Handlers every UI file can use, e.g. <button on:click="reload">. Handlers can't hold the view,
+// so the ones that need it dispatch an action that HelloWorld handles
+pub fn register_default_handlers(registry: &mut xml2gpui::handlers::HandlerRegistry) {
+    registry.register_handler("reload", |cx| cx.dispatch_action(Box::new(Reload)));
+    registry.register_handler("open_file", |cx| cx.dispatch_action(Box::new(OpenFile)));
+    registry.register_handler("export_config", |cx| {
+        cx.dispatch_action(Box::new(ExportConfig))
+    });
+    registry.register_handler("import_config", |cx| {
+        cx.dispatch_action(Box::new(ImportConfig))
+    });
+    registry.register_handler("undo", |cx| cx.dispatch_action(Box::new(Undo)));
+    registry.register_handler("redo", |cx| cx.dispatch_action(Box::new(Redo)));
+    registry.register_handler("save_snapshot", |cx| {
+        cx.dispatch_action(Box::new(SaveSnapshot))
+    });
+    registry.register_handler("copy_sms_commands", |cx| {
+        cx.dispatch_action(Box::new(CopySmsCommands))
+    });
+    registry.register_handler("toggle_dark_mode", |cx| {
+        xml2gpui::theme::Theme::toggle_mode(cx)
+    });
+    registry.register_handler("quit", |cx| cx.quit());
+}
+
+fn content_hash(path: &Path) -> Option<u64> {
+    let content = std::fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
 fn async_watcher() -> notify::Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
     let (mut tx, rx) = channel(1);
 
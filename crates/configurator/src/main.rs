@@ -2,13 +2,20 @@ use gpui::*;
 
 mod assets;
 mod db;
+mod device;
 mod hello;
 mod paths;
 mod theme;
+mod window_startup;
+
+use std::borrow::Cow;
+use std::path::Path;
 
 use assets::Assets;
 use hello::HelloWorld;
-use paths::Paths;
+use paths::{paths, Paths};
+use theme::Theme;
+use window_startup::WindowStartupBehavior;
 
 pub enum CounterEvent {
     Increase { amount: i32 },
@@ -19,16 +26,36 @@ impl EventEmitter<CounterEvent> for HelloWorld {}
 fn main() {
     tracing_subscriber::fmt::init();
 
+    // The config database and the user files live in these
+    if let Err(e) = paths().ensure_all_exist() {
+        tracing::error!("Failed to create the app directories: {}", e);
+    }
+
     App::new().with_assets(Assets).run(|cx: &mut AppContext| {
         load_fonts(cx).expect("Failed to load fonts");
+        Theme::init(cx);
+        cx.set_global(xml2gpui::dev_mode::DevMode::from_env());
 
         // Displays
         let displays = cx.displays();
 
+        let startup = WindowStartupBehavior::load();
+        let preferred = WindowStartupBehavior::display_from_args().or(startup.display);
+
         let mut window_options = WindowOptions::default();
-        window_options.display_id = Some(displays[1].id());
+        if let Some(display) = WindowStartupBehavior::select_display(&displays, preferred) {
+            tracing::info!(
+                "Opening the window on display {:?} at {:?}",
+                display.id(),
+                display.bounds()
+            );
+            window_options.display_id = Some(display.id());
+            startup.apply(&mut window_options, display.bounds());
+        }
 
         cx.open_window(window_options, |cx| {
+            Theme::follow_os_appearance(cx);
+            WindowStartupBehavior::remember_position(cx);
             // Root view
             HelloWorld::new(cx)
         });
@@ -44,5 +71,48 @@ fn load_fonts(cx: &mut AppContext) -> gpui::Result<()> {
             embedded_fonts.push(font_bytes);
         }
     }
-    cx.text_system().add_fonts(embedded_fonts)
+    // Embedded fonts go first, so they are there even if a user font is broken
+    cx.text_system().add_fonts(embedded_fonts)?;
+
+    let user_fonts = load_user_fonts(&paths().fonts_dir());
+    if !user_fonts.is_empty() {
+        if let Err(e) = cx.text_system().add_fonts(user_fonts) {
+            tracing::error!(
+                "Failed to load fonts from {}: {}",
+                paths().fonts_dir().display(),
+                e
+            );
+        }
+    }
+    Ok(())
+}
+
+// .ttf and .otf files of the directory. Having no fonts directory is fine
+fn load_user_fonts(dir: &Path) -> Vec<Cow<'static, [u8]>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            tracing::warn!("Can't read fonts from {}: {}", dir.display(), e);
+            return Vec::new();
+        }
+    };
+
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    extension.eq_ignore_ascii_case("ttf") || extension.eq_ignore_ascii_case("otf")
+                })
+        })
+        .filter_map(|path| match std::fs::read(&path) {
+            Ok(bytes) => Some(Cow::Owned(bytes)),
+            Err(e) => {
+                tracing::warn!("Can't read font {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect()
 }
@@ -1,38 +1,117 @@
 use std::{path::PathBuf, sync::OnceLock};
 
+use crate::assets::UiFiles;
+
 pub struct Paths {
     pub cache: PathBuf,
     pub config: PathBuf,
     pub data: PathBuf,
+    pub log: PathBuf,
 }
 
-pub static NAME: &str = "loungy";
+pub static NAME: &str = "teltonika-configurator";
 
 impl Paths {
+    // Platform directories from dirs: XDG on Linux, Library on macOS and AppData on Windows
     pub fn new() -> Self {
-        let username = whoami::username();
+        // Only without a home directory, which a desktop app doesn't really run without
+        let fallback = || std::env::temp_dir().join(NAME);
+        let app_dir = |dir: Option<PathBuf>| dir.map(|dir| dir.join(NAME)).unwrap_or_else(fallback);
+
         #[cfg(target_os = "macos")]
-        let user_dir = PathBuf::from("/Users").join(username);
+        let log = app_dir(dirs::home_dir().map(|home| home.join("Library/Logs")));
         #[cfg(target_os = "linux")]
-        let user_dir = PathBuf::from("/home").join(username);
+        let log = app_dir(dirs::state_dir()).join("logs");
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+        let log = app_dir(dirs::data_local_dir()).join("logs");
+
         Self {
-            #[cfg(target_os = "macos")]
-            cache: user_dir.clone().join("Library/Caches").join(NAME),
-            #[cfg(target_os = "linux")]
-            cache: user_dir.clone().join(".cache").join(NAME),
-            config: user_dir.clone().join(".config").join(NAME),
-            #[cfg(target_os = "macos")]
-            data: user_dir
-                .clone()
-                .join("Library/Application Support")
-                .join(NAME),
-            #[cfg(target_os = "linux")]
-            data: user_dir.clone().join(".local/share").join(NAME),
+            cache: app_dir(dirs::cache_dir()),
+            config: app_dir(dirs::config_dir()),
+            data: app_dir(dirs::data_dir()),
+            log,
         }
     }
+
+    pub fn ensure_all_exist(&self) -> std::io::Result<()> {
+        for dir in [&self.cache, &self.config, &self.data, &self.log] {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        // First start. Later starts keep the user's edits, and files the user removed stay removed
+        let ui_dir = self.ui_dir();
+        if !ui_dir.exists() {
+            std::fs::create_dir_all(&ui_dir)?;
+            for name in UiFiles::iter() {
+                if let Some(file) = UiFiles::get(&name) {
+                    std::fs::write(ui_dir.join(name.as_ref()), file.data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The user's .gpuiml files, see ensure_all_exist
+    pub fn ui_dir(&self) -> PathBuf {
+        self.config.join("ui")
+    }
+
+    // Extra fonts from the user, e.g. a branding font of a device management profile
+    pub fn fonts_dir(&self) -> PathBuf {
+        self.config.join("fonts")
+    }
 }
 
 pub fn paths() -> &'static Paths {
     static PATHS: OnceLock<Paths> = OnceLock::new();
     PATHS.get_or_init(Paths::new)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths_in(root: &std::path::Path) -> Paths {
+        Paths {
+            cache: root.join("cache"),
+            config: root.join("config"),
+            data: root.join("data"),
+            log: root.join("log"),
+        }
+    }
+
+    #[test]
+    fn ensure_all_exist_creates_the_dirs_and_the_ui_files() {
+        let root = tempfile::tempdir().unwrap();
+        let paths = paths_in(root.path());
+        paths.ensure_all_exist().unwrap();
+
+        for dir in [&paths.cache, &paths.config, &paths.data, &paths.log] {
+            assert!(dir.is_dir(), "{} is missing", dir.display());
+        }
+        let ui = std::fs::read_to_string(paths.ui_dir().join("FMBFAMILY.gpuiml")).unwrap();
+        assert_eq!(
+            ui.as_bytes(),
+            &*UiFiles::get("FMBFAMILY.gpuiml").unwrap().data
+        );
+        assert!(paths.ui_dir().join("FMT100.gpuiml").is_file());
+    }
+
+    #[test]
+    fn ensure_all_exist_keeps_the_users_ui_files() {
+        let root = tempfile::tempdir().unwrap();
+        let paths = paths_in(root.path());
+        paths.ensure_all_exist().unwrap();
+
+        let edited = paths.ui_dir().join("FMBFAMILY.gpuiml");
+        std::fs::write(&edited, "<div>Edited</div>").unwrap();
+        std::fs::remove_file(paths.ui_dir().join("FMT100.gpuiml")).unwrap();
+        paths.ensure_all_exist().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&edited).unwrap(),
+            "<div>Edited</div>"
+        );
+        assert!(!paths.ui_dir().join("FMT100.gpuiml").exists());
+    }
+}
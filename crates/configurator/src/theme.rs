@@ -91,22 +91,17 @@ pub struct Theme {
     pub crust: Hsla,
 }
 
-fn load_fonts(cx: &mut AppContext) -> gpui::Result<()> {
-    let font_paths = cx.asset_source().list("fonts")?;
-    let mut embedded_fonts = Vec::new();
-    for font_path in font_paths {
-        if font_path.ends_with(".ttf") {
-            let font_bytes = cx.asset_source().load(&font_path)?;
-            embedded_fonts.push(font_bytes);
-        }
-    }
-    cx.text_system().add_fonts(embedded_fonts)
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ThemeSettings {
     pub light: String,
     pub dark: String,
+    // Switch between light and dark theme when the OS appearance changes
+    #[serde(default = "default_follow_os")]
+    pub follow_os: bool,
+}
+
+fn default_follow_os() -> bool {
+    true
 }
 
 impl Default for ThemeSettings {
@@ -114,32 +109,47 @@ impl Default for ThemeSettings {
         Self {
             light: "Catppuccin Latte".into(),
             dark: "Catppuccin Mocha".into(),
+            follow_os: default_follow_os(),
         }
     }
 }
 
 impl Theme {
     pub fn init(cx: &mut AppContext) {
-        load_fonts(cx).expect("Failed to load fonts");
         let mode = dark_light::detect();
         let theme = Theme::mode(mode);
         cx.set_global(theme);
-        // Spawn a background task to detect changes in dark/light mode
-        // TODO: Currently bugged see: https://github.com/frewsxcv/rust-dark-light/issues/29
-        // cx.spawn(|mut cx| async move {
-        //     loop {
-        //         let m = dark_light::detect();
-        //         if m != mode {
-        //             mode = m;
-        //             let _ = cx.update_global::<Theme, _>(|theme: &mut Theme, cx| {
-        //                 *theme = Theme::mode(mode);
-        //                 cx.refresh();
-        //             });
-        //         }
-        //         cx.background_executor().timer(Duration::from_secs(1)).await;
-        //     }
-        // })
-        // .detach();
+        // Polling dark_light for changes is bugged, see: https://github.com/frewsxcv/rust-dark-light/issues/29
+        // Changes are followed with the window appearance observer instead, see follow_os_appearance
+    }
+
+    // Keep the theme in sync with the OS appearance (NSApp.effectiveAppearance on macOS,
+    // the freedesktop color-scheme setting on Linux) unless follow_os is disabled
+    pub fn follow_os_appearance(cx: &mut WindowContext) {
+        // UI files start in the OS mode, toggle_dark_mode switches it until the OS changes again
+        let dark = is_dark(cx.appearance());
+        xml2gpui::theme::Theme::set_mode(ui_mode(dark), cx);
+
+        cx.observe_window_appearance(|cx| {
+            let settings = db().get::<ThemeSettings>("theme").unwrap_or_default();
+            if settings.follow_os {
+                let dark = is_dark(cx.appearance());
+                Theme::set_dark(dark, cx);
+                xml2gpui::theme::Theme::set_mode(ui_mode(dark), cx);
+            }
+        })
+        .detach();
+    }
+
+    pub fn set_dark(dark: bool, cx: &mut AppContext) {
+        let mode = if dark {
+            dark_light::Mode::Dark
+        } else {
+            dark_light::Mode::Light
+        };
+        cx.set_global(Theme::mode(mode));
+        // Re-render all windows with the new theme
+        cx.refresh();
     }
     pub fn mode(mode: dark_light::Mode) -> Theme {
         let settings = db().get::<ThemeSettings>("theme").unwrap_or_default();
@@ -197,3 +207,18 @@ impl Theme {
 }
 
 impl Global for Theme {}
+
+fn is_dark(appearance: WindowAppearance) -> bool {
+    matches!(
+        appearance,
+        WindowAppearance::Dark | WindowAppearance::VibrantDark
+    )
+}
+
+fn ui_mode(dark: bool) -> xml2gpui::theme::ThemeMode {
+    if dark {
+        xml2gpui::theme::ThemeMode::Dark
+    } else {
+        xml2gpui::theme::ThemeMode::Light
+    }
+}
@@ -0,0 +1,189 @@
+use gpui::*;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use xml2gpui::components::input::select::{InputSelect, InputSelectEvent, SelectOption};
+
+use crate::db::db;
+
+const SETTINGS_KEY: &str = "window_startup";
+const LAST_POSITION_KEY: &str = "window_last_position";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum StartupMode {
+    #[default]
+    Center,
+    LastUsed,
+    // Window origin relative to the display origin
+    Specific(i32, i32),
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct WindowStartupBehavior {
+    pub mode: StartupMode,
+    // Index in cx.displays(), overridden by --display N
+    #[serde(default)]
+    pub display: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct LastWindowPosition {
+    x: f64,
+    y: f64,
+}
+
+impl WindowStartupBehavior {
+    pub fn load() -> Self {
+        db().get::<Self>(SETTINGS_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        db().set(SETTINGS_KEY, self)?;
+        Ok(())
+    }
+
+    // Sets the window position to the options before the window is opened
+    pub fn apply(&self, options: &mut WindowOptions, display_bounds: Bounds<GlobalPixels>) {
+        let origin = match self.mode {
+            // Centers on the display of display_id. GPUI doesn't tell the cursor position
+            // before a window exists, so the display is not chosen by the cursor
+            StartupMode::Center => None,
+            StartupMode::LastUsed => db()
+                .get::<LastWindowPosition>(LAST_POSITION_KEY)
+                .map(|position| point(position.x.into(), position.y.into())),
+            StartupMode::Specific(x, y) => {
+                Some(display_bounds.origin + point((x as f64).into(), (y as f64).into()))
+            }
+        };
+
+        match origin {
+            Some(origin) => {
+                options.center = false;
+                options.bounds = WindowBounds::Fixed(Bounds {
+                    origin,
+                    size: size(1280.0.into(), 800.0.into()),
+                });
+            }
+            None => options.center = true,
+        }
+    }
+
+    // Preferred display from the command line, e.g. --display 1 or --display=1
+    pub fn display_from_args() -> Option<usize> {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let value = if arg == "--display" {
+                args.next()
+            } else if let Some(value) = arg.strip_prefix("--display=") {
+                Some(value.to_string())
+            } else {
+                continue;
+            };
+            match value.as_deref().map(str::parse) {
+                Some(Ok(index)) => return Some(index),
+                _ => error!("--display needs a display number, e.g. --display 1"),
+            }
+        }
+        None
+    }
+
+    // Preferred display when it is connected, otherwise the first one. None without displays
+    pub fn select_display<T: Clone>(displays: &[T], preferred: Option<usize>) -> Option<T> {
+        if let Some(index) = preferred {
+            match displays.get(index) {
+                Some(display) => return Some(display.clone()),
+                None => tracing::warn!(
+                    "Display {} is not connected, there are {} displays",
+                    index,
+                    displays.len()
+                ),
+            }
+        }
+        displays.first().cloned()
+    }
+
+    // Lists the connected displays and saves the selected one for the next start
+    pub fn display_picker(cx: &mut WindowContext) -> View<InputSelect> {
+        let options = cx
+            .displays()
+            .iter()
+            .enumerate()
+            .map(|(index, display)| {
+                let size = display.bounds().size;
+                SelectOption::new(
+                    index.to_string(),
+                    format!(
+                        "Display {} ({}×{})",
+                        index + 1,
+                        f64::from(size.width),
+                        f64::from(size.height)
+                    ),
+                )
+            })
+            .collect();
+        let selected = Self::load().display.unwrap_or(0);
+
+        let picker = cx.new_view(|cx| {
+            InputSelect::new(cx)
+                .options(options)
+                .selected(selected.to_string())
+        });
+        cx.subscribe(&picker, |_, event, _cx| match event {
+            InputSelectEvent::Selected(value) => {
+                let mut behavior = Self::load();
+                behavior.display = value.parse().ok();
+                if let Err(e) = behavior.save() {
+                    error!("Failed to save the display: {}", e);
+                }
+            }
+        })
+        .detach();
+        picker
+    }
+
+    // Stores the window position whenever it changes, for StartupMode::LastUsed
+    pub fn remember_position(cx: &mut WindowContext) {
+        cx.observe_window_bounds(|cx| {
+            if let WindowBounds::Fixed(bounds) = cx.window_bounds() {
+                let position = LastWindowPosition {
+                    x: f64::from(bounds.origin.x),
+                    y: f64::from(bounds.origin.y),
+                };
+                if let Err(e) = db().set(LAST_POSITION_KEY, &position) {
+                    error!("Failed to save window position: {}", e);
+                }
+            }
+        })
+        .detach();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_display_uses_the_preferred_display() {
+        let displays = ["first", "second", "third"];
+        assert_eq!(
+            WindowStartupBehavior::select_display(&displays, Some(2)),
+            Some("third")
+        );
+        assert_eq!(
+            WindowStartupBehavior::select_display(&displays, None),
+            Some("first")
+        );
+    }
+
+    #[test]
+    fn select_display_falls_back_to_the_first_display() {
+        let displays = ["first", "second"];
+        assert_eq!(
+            WindowStartupBehavior::select_display(&displays, Some(5)),
+            Some("first")
+        );
+        assert_eq!(
+            WindowStartupBehavior::select_display::<&str>(&[], Some(0)),
+            None
+        );
+    }
+}
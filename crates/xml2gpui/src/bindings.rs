@@ -0,0 +1,22 @@
+use gpui::Model;
+use std::collections::HashMap;
+
+// Application state that inputs can be bound to with bind="key" in the XML
+#[derive(Default, Clone)]
+pub struct BindingRegistry {
+    models: HashMap<String, Model<String>>,
+}
+
+impl BindingRegistry {
+    pub fn bind(&mut self, key: impl Into<String>, model: Model<String>) {
+        self.models.insert(key.into(), model);
+    }
+
+    pub fn get(&self, key: &str) -> Option<Model<String>> {
+        self.models.get(key).cloned()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Model<String>)> {
+        self.models.iter().map(|(key, model)| (key.as_str(), model))
+    }
+}
@@ -0,0 +1,25 @@
+use gpui::*;
+
+// Tailwind's palette at the steps the named color classes support, e.g. border-red-500
+const SHADES: [u16; 5] = [100, 300, 500, 700, 900];
+const TAILWIND_COLORS: [(&str, [u32; 5]); 10] = [
+    ("gray", [0xf3f4f6, 0xd1d5db, 0x6b7280, 0x374151, 0x111827]),
+    ("red", [0xfee2e2, 0xfca5a5, 0xef4444, 0xb91c1c, 0x7f1d1d]),
+    ("orange", [0xffedd5, 0xfdba74, 0xf97316, 0xc2410c, 0x7c2d12]),
+    ("yellow", [0xfef9c3, 0xfde047, 0xeab308, 0xa16207, 0x713f12]),
+    ("green", [0xdcfce7, 0x86efac, 0x22c55e, 0x15803d, 0x14532d]),
+    ("teal", [0xccfbf1, 0x5eead4, 0x14b8a6, 0x0f766e, 0x134e4a]),
+    ("blue", [0xdbeafe, 0x93c5fd, 0x3b82f6, 0x1d4ed8, 0x1e3a8a]),
+    ("indigo", [0xe0e7ff, 0xa5b4fc, 0x6366f1, 0x4338ca, 0x312e81]),
+    ("purple", [0xf3e8ff, 0xd8b4fe, 0xa855f7, 0x7e22ce, 0x581c87]),
+    ("pink", [0xfce7f3, 0xf9a8d4, 0xec4899, 0xbe185d, 0x831843]),
+];
+
+// Color from a name like "red-500", None for unknown hues and shades
+pub fn tailwind_color(name: &str) -> Option<Rgba> {
+    let (hue, shade) = name.rsplit_once('-')?;
+    let shade = shade.parse::<u16>().ok()?;
+    let (_, colors) = TAILWIND_COLORS.iter().find(|(name, _)| *name == hue)?;
+    let index = SHADES.iter().position(|s| *s == shade)?;
+    Some(rgb(colors[index]))
+}
@@ -0,0 +1,106 @@
+use gpui::*;
+
+use crate::bindings::BindingRegistry;
+use crate::context::RenderContext;
+use crate::handlers::HandlerRegistry;
+use crate::suggestions::SuggestionsRegistry;
+use crate::tree::{ChildRenderer, Component, ViewCache};
+
+// Collapsible section with a clickable title row
+pub struct AccordionSection {
+    title: String,
+    // Children of the <section> element
+    body: Vec<Component>,
+    expanded: bool,
+    renderer: ChildRenderer,
+    style: StyleRefinement,
+}
+
+impl AccordionSection {
+    pub fn new(title: impl Into<String>, body: Vec<Component>) -> Self {
+        Self {
+            title: title.into(),
+            body,
+            expanded: false,
+            renderer: ChildRenderer::default(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    pub fn toggle(&mut self, cx: &mut ViewContext<Self>) {
+        self.expanded = !self.expanded;
+        cx.notify();
+    }
+
+    pub(crate) fn set_registries(
+        &mut self,
+        views: &ViewCache,
+        handlers: &HandlerRegistry,
+        bindings: &BindingRegistry,
+        suggestions: &SuggestionsRegistry,
+        context: &RenderContext,
+    ) {
+        self.renderer
+            .update(views, handlers, bindings, suggestions, context);
+    }
+}
+
+impl Render for AccordionSection {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let header = div()
+            .id("accordion-header")
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_2()
+            .py_1()
+            .cursor_pointer()
+            .font_weight(FontWeight::BOLD)
+            .child(
+                svg()
+                    .path(if self.expanded {
+                        "icons/chevron-down.svg"
+                    } else {
+                        "icons/chevron-right.svg"
+                    })
+                    .size_4()
+                    .text_color(rgb(0x666666)),
+            )
+            .child(self.title.clone())
+            .on_click(cx.listener(|this, _event, cx| this.toggle(cx)));
+
+        let mut element = div()
+            .flex()
+            .flex_col()
+            .border_b_1()
+            .border_color(rgb(0xcccccc))
+            .child(header);
+
+        if self.expanded {
+            let mut body = div().flex().flex_col().px_2().pb_2();
+            for child in &self.body {
+                body = body.child(self.renderer.render(child, cx));
+            }
+            element = element.child(body);
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for AccordionSection {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
@@ -0,0 +1,115 @@
+use gpui::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Severity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+    Success,
+}
+
+impl Severity {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "info" => Some(Severity::Info),
+            "warning" => Some(Severity::Warning),
+            "error" => Some(Severity::Error),
+            "success" => Some(Severity::Success),
+            _ => None,
+        }
+    }
+
+    // Icon, background and foreground color
+    fn look(self) -> (&'static str, Rgba, Rgba) {
+        match self {
+            Severity::Info => ("icons/info.svg", rgb(0xdbeafe), rgb(0x1e40af)),
+            Severity::Warning => ("icons/alert-triangle.svg", rgb(0xffedd5), rgb(0xc2410c)),
+            Severity::Error => ("icons/x-circle.svg", rgb(0xfee2e2), rgb(0xb91c1c)),
+            Severity::Success => ("icons/check-circle.svg", rgb(0xdcfce7), rgb(0x15803d)),
+        }
+    }
+}
+
+// Message box colored by its severity. A view, because a dismissible alert remembers that it
+// was dismissed
+pub struct Alert {
+    severity: Severity,
+    message: String,
+    dismissible: bool,
+    dismissed: bool,
+    style: StyleRefinement,
+}
+
+impl Alert {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            dismissible: false,
+            dismissed: false,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    // Message can change with the render context, the severity and dismissed state stay
+    pub fn set_message(&mut self, message: String, cx: &mut ViewContext<Self>) {
+        if message != self.message {
+            self.message = message;
+            cx.notify();
+        }
+    }
+
+    pub fn dismiss(&mut self, cx: &mut ViewContext<Self>) {
+        self.dismissed = true;
+        cx.notify();
+    }
+}
+
+impl Render for Alert {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if self.dismissed {
+            return div().into_any_element();
+        }
+
+        let (icon, background, foreground) = self.severity.look();
+        let mut element = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .m_1()
+            .px_3()
+            .py_2()
+            .rounded_md()
+            .bg(background)
+            .text_color(foreground)
+            .child(svg().path(icon).size_4().flex_none().text_color(foreground))
+            .child(div().flex_1().child(self.message.clone()));
+
+        if self.dismissible {
+            element = element.child(
+                div()
+                    .id("alert-dismiss")
+                    .flex_none()
+                    .cursor_pointer()
+                    .child(svg().path("icons/x.svg").size_4().text_color(foreground))
+                    .on_click(cx.listener(|this, _event, cx| this.dismiss(cx))),
+            );
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element.into_any_element()
+    }
+}
+
+impl Styled for Alert {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
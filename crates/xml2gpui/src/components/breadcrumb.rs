@@ -0,0 +1,138 @@
+use gpui::*;
+
+use crate::handlers::Handler;
+
+pub struct Crumb {
+    pub label: SharedString,
+    pub on_click: Option<Handler>,
+}
+
+impl Crumb {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            on_click: None,
+        }
+    }
+
+    pub fn on_click(mut self, handler: Handler) -> Self {
+        self.on_click = Some(handler);
+        self
+    }
+}
+
+// Trail of sections, e.g. Home > GPS > Filter. All but the last crumb are links
+#[derive(IntoElement)]
+pub struct Breadcrumb {
+    crumbs: Vec<Crumb>,
+    style: StyleRefinement,
+}
+
+impl Breadcrumb {
+    pub fn new(crumbs: Vec<Crumb>) -> Self {
+        Self {
+            crumbs,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn crumbs(&self) -> &[Crumb] {
+        &self.crumbs
+    }
+}
+
+impl RenderOnce for Breadcrumb {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let last = self.crumbs.len().saturating_sub(1);
+        let mut element = div().flex().items_center().gap_1().m_1();
+
+        for (index, crumb) in self.crumbs.into_iter().enumerate() {
+            if index > 0 {
+                element = element.child(div().text_color(rgb(0x999999)).child(">"));
+            }
+
+            // Current section
+            if index == last {
+                element = element.child(div().font_weight(FontWeight::BOLD).child(crumb.label));
+                continue;
+            }
+
+            let mut link = div()
+                .id(("crumb", index))
+                .text_color(rgb(0x0055ff))
+                .cursor_pointer()
+                .hover(|style| style.text_color(rgb(0x003399)))
+                .child(crumb.label);
+            if let Some(handler) = crumb.on_click {
+                link = link.on_click(move |_event, cx| handler(cx));
+            }
+            element = element.child(link);
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for Breadcrumb {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Trail {
+        clicks: Arc<AtomicUsize>,
+    }
+
+    impl Trail {
+        fn breadcrumb(&self) -> Breadcrumb {
+            let clicks = self.clicks.clone();
+            let handler: Handler = Arc::new(move |_cx| {
+                clicks.fetch_add(1, Ordering::SeqCst);
+            });
+            Breadcrumb::new(vec![
+                Crumb::new("Home").on_click(handler),
+                Crumb::new("GPS"),
+                Crumb::new("Filter"),
+            ])
+        }
+    }
+
+    impl Render for Trail {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            self.breadcrumb()
+        }
+    }
+
+    #[test]
+    fn keeps_crumbs_in_order() {
+        let breadcrumb = Breadcrumb::new(vec![Crumb::new("Home"), Crumb::new("GPS")]);
+        let labels: Vec<_> = breadcrumb.crumbs().iter().map(|c| c.label.clone()).collect();
+        assert_eq!(labels, ["Home", "GPS"]);
+        assert!(breadcrumb.crumbs().iter().all(|c| c.on_click.is_none()));
+    }
+
+    #[gpui::test]
+    fn renders_and_runs_the_crumb_handler(cx: &mut TestAppContext) {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let (trail, cx) = cx.add_window_view(|_cx| Trail {
+            clicks: clicks.clone(),
+        });
+        cx.run_until_parked();
+        // Rendering doesn't click anything
+        assert_eq!(clicks.load(Ordering::SeqCst), 0);
+
+        let breadcrumb = trail.update(cx, |trail, _| trail.breadcrumb());
+        let handler = breadcrumb.crumbs()[0].on_click.clone().unwrap();
+        cx.update(|cx| handler(cx));
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+        assert!(breadcrumb.crumbs()[2].on_click.is_none());
+    }
+}
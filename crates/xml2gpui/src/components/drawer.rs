@@ -0,0 +1,351 @@
+use gpui::*;
+use std::time::Duration;
+
+use crate::bindings::BindingRegistry;
+use crate::context::RenderContext;
+use crate::handlers::{Handler, HandlerRegistry};
+use crate::suggestions::SuggestionsRegistry;
+use crate::tree::{ChildRenderer, Component, ViewCache};
+
+pub enum DrawerEvent {
+    Closed,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DrawerSide {
+    Left,
+    #[default]
+    Right,
+}
+
+impl DrawerSide {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "left" => Some(DrawerSide::Left),
+            "right" => Some(DrawerSide::Right),
+            _ => None,
+        }
+    }
+}
+
+// Frames the drawer takes to slide in or out
+const SLIDE_STEPS: usize = 8;
+const WIDTH: f32 = 320.0;
+
+// Side panel that slides over the window. Opened from the XML with on:click="drawer:<id>"
+pub struct Drawer {
+    title: String,
+    // Children of the <drawer> element
+    body: Vec<Component>,
+    side: DrawerSide,
+    open: bool,
+    // Last open="..." from the XML
+    open_attribute: bool,
+    // How far the drawer is in, from hidden (0.0) to open (1.0)
+    position: f32,
+    on_close: Option<Handler>,
+    renderer: ChildRenderer,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<DrawerEvent> for Drawer {}
+
+impl Drawer {
+    pub fn new(body: Vec<Component>) -> Self {
+        Self {
+            title: String::new(),
+            body,
+            side: DrawerSide::default(),
+            open: false,
+            open_attribute: false,
+            position: 0.0,
+            on_close: None,
+            renderer: ChildRenderer::default(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn side(mut self, side: DrawerSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    // Starts open, without sliding in
+    pub fn open_at_start(mut self, open: bool) -> Self {
+        self.open = open;
+        self.open_attribute = open;
+        self.position = if open { 1.0 } else { 0.0 };
+        self
+    }
+
+    pub fn on_close(mut self, handler: Handler) -> Self {
+        self.on_close = Some(handler);
+        self
+    }
+
+    // Title and side can come from the context. open="..." only opens or closes the drawer when
+    // it changes, so re-rendering doesn't reopen a drawer the user closed
+    pub fn update_attributes(
+        &mut self,
+        title: String,
+        side: DrawerSide,
+        open: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if title != self.title || side != self.side {
+            self.title = title;
+            self.side = side;
+            cx.notify();
+        }
+        if open != self.open_attribute {
+            self.open_attribute = open;
+            // Attributes are set while the parent is rendered, which is no place for the
+            // on:close handler to update other views
+            cx.defer(move |this, cx| {
+                if open {
+                    this.open(cx);
+                } else {
+                    this.close(cx);
+                }
+            });
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self, cx: &mut ViewContext<Self>) {
+        if self.open {
+            return;
+        }
+        self.open = true;
+        self.slide(cx);
+    }
+
+    pub fn close(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            return;
+        }
+        self.open = false;
+        cx.emit(DrawerEvent::Closed);
+        if let Some(handler) = self.on_close.clone() {
+            handler(cx);
+        }
+        self.slide(cx);
+    }
+
+    pub(crate) fn set_registries(
+        &mut self,
+        views: &ViewCache,
+        handlers: &HandlerRegistry,
+        bindings: &BindingRegistry,
+        suggestions: &SuggestionsRegistry,
+        context: &RenderContext,
+    ) {
+        self.renderer
+            .update(views, handlers, bindings, suggestions, context);
+    }
+
+    // Moves the drawer to the current state a step per frame
+    fn slide(&mut self, cx: &mut ViewContext<Self>) {
+        cx.spawn(|this, mut cx| async move {
+            for _ in 0..SLIDE_STEPS {
+                cx.background_executor()
+                    .timer(Duration::from_millis(16))
+                    .await;
+                let updated = this.update(&mut cx, |this, cx| {
+                    let step = 1.0 / SLIDE_STEPS as f32;
+                    this.position = if this.open {
+                        (this.position + step).min(1.0)
+                    } else {
+                        (this.position - step).max(0.0)
+                    };
+                    cx.notify();
+                });
+                // View is gone
+                if updated.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+}
+
+impl Render for Drawer {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // Fully slid out
+        if !self.open && self.position <= 0.0 {
+            return div().into_any_element();
+        }
+
+        let header = div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .pb_2()
+            .font_weight(FontWeight::BOLD)
+            .child(self.title.clone())
+            .child(
+                div()
+                    .id("drawer-close")
+                    .px_2()
+                    .cursor_pointer()
+                    .child("×")
+                    .on_click(cx.listener(|this, _event, cx| this.close(cx))),
+            );
+
+        let mut body = div().flex().flex_col().gap_2();
+        for child in &self.body {
+            body = body.child(self.renderer.render(child, cx));
+        }
+
+        let mut panel = div()
+            .id("drawer")
+            .absolute()
+            .top_0()
+            .h_full()
+            .w(px(WIDTH))
+            .flex()
+            .flex_col()
+            .p_4()
+            .bg(rgb(0xffffff))
+            .shadow_lg()
+            .overflow_y_scroll()
+            // Clicks inside the panel don't reach the backdrop
+            .on_mouse_down(MouseButton::Left, |_event, cx| cx.stop_propagation())
+            .child(header)
+            .child(body);
+        // Hidden part of the panel is outside the window
+        let offset = px(-WIDTH * (1.0 - self.position));
+        panel = match self.side {
+            DrawerSide::Left => panel.left(offset),
+            DrawerSide::Right => panel.right(offset),
+        };
+        // Classes from the XML are applied on top of the default look
+        panel.style().refine(&self.style);
+
+        // Overlay is positioned in window coordinates like the modal's, so the backdrop covers
+        // the window wherever the <drawer> element is in the tree
+        let viewport = cx.viewport_size();
+        overlay()
+            .position(point(px(0.0), px(0.0)))
+            .child(
+                div()
+                    .id("drawer-backdrop")
+                    .relative()
+                    .w(viewport.width)
+                    .h(viewport.height)
+                    .bg(hsla(0.0, 0.0, 0.0, 0.4 * self.position))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, _event, cx| this.close(cx)),
+                    )
+                    .child(panel),
+            )
+            .into_any_element()
+    }
+}
+
+impl Styled for Drawer {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn slide_done(cx: &mut VisualTestContext) {
+        cx.executor()
+            .advance_clock(Duration::from_millis(16 * SLIDE_STEPS as u64));
+        cx.run_until_parked();
+    }
+
+    fn state(drawer: &View<Drawer>, cx: &mut VisualTestContext) -> (bool, f32) {
+        drawer.update(cx, |drawer, _| (drawer.is_open(), drawer.position))
+    }
+
+    #[test]
+    fn parses_sides() {
+        assert_eq!(DrawerSide::parse("left"), Some(DrawerSide::Left));
+        assert_eq!(DrawerSide::parse("right"), Some(DrawerSide::Right));
+        assert_eq!(DrawerSide::parse("top"), None);
+    }
+
+    #[gpui::test]
+    fn opens_and_slides_in(cx: &mut TestAppContext) {
+        let (drawer, cx) = cx.add_window_view(|_cx| Drawer::new(Vec::new()));
+        assert_eq!(state(&drawer, cx), (false, 0.0));
+
+        drawer.update(cx, |drawer, cx| drawer.open(cx));
+        slide_done(cx);
+        let (open, position) = state(&drawer, cx);
+        assert!(open);
+        assert!((position - 1.0).abs() < 1e-6);
+    }
+
+    #[gpui::test]
+    fn close_emits_closed_and_runs_the_handler(cx: &mut TestAppContext) {
+        let closes = Arc::new(AtomicUsize::new(0));
+        let handler: Handler = {
+            let closes = closes.clone();
+            Arc::new(move |_cx| {
+                closes.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        let (drawer, cx) = cx.add_window_view(|_cx| {
+            Drawer::new(Vec::new())
+                .open_at_start(true)
+                .on_close(handler)
+        });
+        let events = Arc::new(AtomicUsize::new(0));
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(&drawer, move |_, _event: &DrawerEvent, _| {
+                events.fetch_add(1, Ordering::SeqCst);
+            })
+            .detach();
+        });
+
+        // What the close button and the backdrop do
+        drawer.update(cx, |drawer, cx| drawer.close(cx));
+        // Closing a closed drawer does nothing
+        drawer.update(cx, |drawer, cx| drawer.close(cx));
+        slide_done(cx);
+        assert_eq!(events.load(Ordering::SeqCst), 1);
+        assert_eq!(closes.load(Ordering::SeqCst), 1);
+        assert_eq!(state(&drawer, cx), (false, 0.0));
+    }
+
+    #[gpui::test]
+    fn update_attributes_follows_the_xml(cx: &mut TestAppContext) {
+        let (drawer, cx) = cx.add_window_view(|_cx| Drawer::new(Vec::new()).title("GPS"));
+
+        drawer.update(cx, |drawer, cx| {
+            drawer.update_attributes("GPS (2 changes)".into(), DrawerSide::Left, true, cx)
+        });
+        cx.run_until_parked();
+        let (title, side) = drawer.update(cx, |drawer, _| (drawer.title.clone(), drawer.side));
+        assert_eq!(title, "GPS (2 changes)");
+        assert_eq!(side, DrawerSide::Left);
+        assert!(state(&drawer, cx).0);
+
+        // The user closes it, rendering with the same open="true" keeps it closed
+        drawer.update(cx, |drawer, cx| drawer.close(cx));
+        drawer.update(cx, |drawer, cx| {
+            drawer.update_attributes("GPS".into(), DrawerSide::Left, true, cx)
+        });
+        cx.run_until_parked();
+        assert!(!state(&drawer, cx).0);
+    }
+}
@@ -0,0 +1,144 @@
+use gpui::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientDirection {
+    ToTop,
+    ToTopRight,
+    ToRight,
+    ToBottomRight,
+    ToBottom,
+    ToBottomLeft,
+    ToLeft,
+    ToTopLeft,
+}
+
+impl GradientDirection {
+    // Suffix of the Tailwind class, e.g. "r" from bg-gradient-to-r
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "t" => Some(GradientDirection::ToTop),
+            "tr" => Some(GradientDirection::ToTopRight),
+            "r" => Some(GradientDirection::ToRight),
+            "br" => Some(GradientDirection::ToBottomRight),
+            "b" => Some(GradientDirection::ToBottom),
+            "bl" => Some(GradientDirection::ToBottomLeft),
+            "l" => Some(GradientDirection::ToLeft),
+            "tl" => Some(GradientDirection::ToTopLeft),
+            _ => None,
+        }
+    }
+
+    // Which way x and y grow towards the end color
+    fn vector(self) -> (f32, f32) {
+        match self {
+            GradientDirection::ToTop => (0.0, -1.0),
+            GradientDirection::ToTopRight => (1.0, -1.0),
+            GradientDirection::ToRight => (1.0, 0.0),
+            GradientDirection::ToBottomRight => (1.0, 1.0),
+            GradientDirection::ToBottom => (0.0, 1.0),
+            GradientDirection::ToBottomLeft => (-1.0, 1.0),
+            GradientDirection::ToLeft => (-1.0, 0.0),
+            GradientDirection::ToTopLeft => (-1.0, -1.0),
+        }
+    }
+}
+
+// Most cells a gradient is painted with along one axis
+const MAX_CELLS: f32 = 128.0;
+// Diagonal gradients need a grid of cells, so they get fewer per axis
+const MAX_DIAGONAL_CELLS: f32 = 48.0;
+
+// Linear gradient that fills its parent. GPUI can only paint solid quads, so the gradient is
+// painted as bands (or a grid for diagonals) of interpolated colors. It doesn't follow the
+// parent's rounded corners
+#[derive(IntoElement)]
+pub struct Gradient {
+    direction: GradientDirection,
+    from: Rgba,
+    via: Option<Rgba>,
+    to: Rgba,
+}
+
+impl Gradient {
+    pub fn new(direction: GradientDirection, from: Rgba, to: Rgba) -> Self {
+        Self {
+            direction,
+            from,
+            via: None,
+            to,
+        }
+    }
+
+    pub fn via(mut self, via: Rgba) -> Self {
+        self.via = Some(via);
+        self
+    }
+
+    // Color at 0.0..=1.0 along the gradient
+    pub fn color_at(&self, t: f32) -> Rgba {
+        let t = t.clamp(0.0, 1.0);
+        match self.via {
+            Some(via) if t < 0.5 => mix(self.from, via, t * 2.0),
+            Some(via) => mix(via, self.to, (t - 0.5) * 2.0),
+            None => mix(self.from, self.to, t),
+        }
+    }
+}
+
+fn mix(from: Rgba, to: Rgba, t: f32) -> Rgba {
+    Rgba {
+        r: from.r + (to.r - from.r) * t,
+        g: from.g + (to.g - from.g) * t,
+        b: from.b + (to.b - from.b) * t,
+        a: from.a + (to.a - from.a) * t,
+    }
+}
+
+impl RenderOnce for Gradient {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        canvas(move |bounds, cx| {
+            let (dx, dy) = self.direction.vector();
+            let max_cells = if dx != 0.0 && dy != 0.0 {
+                MAX_DIAGONAL_CELLS
+            } else {
+                MAX_CELLS
+            };
+            // Cells are at least 2px, and only one along the axis the color doesn't change
+            let cells = |length: Pixels, delta: f32| {
+                if delta == 0.0 {
+                    1
+                } else {
+                    ((length / px(2.0)).ceil()).clamp(1.0, max_cells) as usize
+                }
+            };
+            let columns = cells(bounds.size.width, dx);
+            let rows = cells(bounds.size.height, dy);
+            let cell_size = size(
+                bounds.size.width / columns as f32,
+                bounds.size.height / rows as f32,
+            );
+
+            for row in 0..rows {
+                for column in 0..columns {
+                    // Position of the cell center from 0.0 to 1.0, flipped when the gradient
+                    // goes left or up
+                    let x = (column as f32 + 0.5) / columns as f32;
+                    let y = (row as f32 + 0.5) / rows as f32;
+                    let x = if dx < 0.0 { 1.0 - x } else { x };
+                    let y = if dy < 0.0 { 1.0 - y } else { y };
+                    let t = (x * dx.abs() + y * dy.abs()) / (dx.abs() + dy.abs());
+
+                    let origin = point(
+                        bounds.origin.x + cell_size.width * column as f32,
+                        bounds.origin.y + cell_size.height * row as f32,
+                    );
+                    cx.paint_quad(fill(Bounds::new(origin, cell_size), self.color_at(t)));
+                }
+            }
+        })
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+    }
+}
@@ -0,0 +1,374 @@
+use gpui::*;
+use std::time::Duration;
+
+use crate::suggestions::SuggestionsProvider;
+
+pub enum InputAutoCompleteEvent {
+    ValueChanged(String),
+    Selected(String),
+}
+
+// Typing faster than this asks the provider only once
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+// Text input with a dropdown of suggestions for the typed text
+pub struct InputAutoComplete {
+    value: String,
+    placeholder: SharedString,
+    suggestions_provider: Option<SuggestionsProvider>,
+    suggestions: Vec<String>,
+    // Suggestion picked with the arrow keys
+    highlighted: Option<usize>,
+    open: bool,
+    // Replacing the task drops the previous one, which cancels its timer
+    pending: Option<Task<()>>,
+    // Model the value is kept in sync with, both ways
+    binding: Option<(Model<String>, Subscription)>,
+    focus_handle: FocusHandle,
+    _blur_subscription: Subscription,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<InputAutoCompleteEvent> for InputAutoComplete {}
+
+impl InputAutoComplete {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let blur_subscription = cx.on_blur(&focus_handle, |this, cx| {
+            this.close(cx);
+        });
+
+        Self {
+            value: String::new(),
+            placeholder: SharedString::default(),
+            suggestions_provider: None,
+            suggestions: Vec::new(),
+            highlighted: None,
+            open: false,
+            pending: None,
+            binding: None,
+            focus_handle,
+            _blur_subscription: blur_subscription,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn suggestions_provider(mut self, provider: SuggestionsProvider) -> Self {
+        self.suggestions_provider = Some(provider);
+        self
+    }
+
+    // Shows the model's value and writes typed and selected values back to it
+    pub fn bind(mut self, model: Model<String>, cx: &mut ViewContext<Self>) -> Self {
+        self.value = model.read(cx).clone();
+        let subscription = cx.observe(&model, |this, model, cx| {
+            let value = model.read(cx);
+            if *value != this.value {
+                this.value = value.clone();
+                cx.notify();
+            }
+        });
+        self.binding = Some((model, subscription));
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.value
+    }
+
+    pub fn select(&mut self, suggestion: String, cx: &mut ViewContext<Self>) {
+        self.value = suggestion.clone();
+        self.write_binding(cx);
+        self.close(cx);
+        cx.emit(InputAutoCompleteEvent::Selected(suggestion));
+    }
+
+    fn close(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        self.highlighted = None;
+        self.pending = None;
+        cx.notify();
+    }
+
+    fn write_binding(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some((model, _)) = &self.binding {
+            let value = self.value.clone();
+            model.update(cx, |model, cx| {
+                *model = value;
+                cx.notify();
+            });
+        }
+    }
+
+    // Asks the provider once the typing has paused
+    fn schedule_suggestions(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(provider) = self.suggestions_provider.clone() else {
+            return;
+        };
+        let query = self.value.clone();
+
+        self.pending = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(DEBOUNCE).await;
+            // Providers may be slow (e.g. reading a file), so they don't run on the UI thread
+            let suggestions = cx
+                .background_executor()
+                .spawn(async move { provider(&query) })
+                .await;
+            // View is gone when this fails, nothing to update
+            this.update(&mut cx, |this, cx| {
+                this.open = !suggestions.is_empty();
+                this.suggestions = suggestions;
+                this.highlighted = None;
+                this.pending = None;
+                cx.notify();
+            })
+            .ok();
+        }));
+    }
+
+    fn move_highlight(&mut self, down: bool, cx: &mut ViewContext<Self>) {
+        if !self.open || self.suggestions.is_empty() {
+            return;
+        }
+        let last = self.suggestions.len() - 1;
+        self.highlighted = Some(match (self.highlighted, down) {
+            (None, true) => 0,
+            (None, false) => last,
+            (Some(ix), true) => (ix + 1).min(last),
+            (Some(ix), false) => ix.saturating_sub(1),
+        });
+        cx.notify();
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+
+        // Leave shortcuts to the application
+        if keystroke.modifiers.control || keystroke.modifiers.command {
+            return;
+        }
+
+        match keystroke.key.as_str() {
+            "down" => return self.move_highlight(true, cx),
+            "up" => return self.move_highlight(false, cx),
+            "enter" => {
+                let highlighted = self
+                    .highlighted
+                    .filter(|_| self.open)
+                    .and_then(|ix| self.suggestions.get(ix).cloned());
+                if let Some(suggestion) = highlighted {
+                    self.select(suggestion, cx);
+                }
+                return;
+            }
+            "escape" => return self.close(cx),
+            "backspace" => {
+                if self.value.pop().is_none() {
+                    return;
+                }
+            }
+            // ime_key has the actual typed character (shift etc. applied)
+            _ => match &keystroke.ime_key {
+                Some(typed) => self.value.push_str(typed),
+                None if keystroke.key.chars().count() == 1 => self.value.push_str(&keystroke.key),
+                None => return,
+            },
+        }
+
+        self.write_binding(cx);
+        self.schedule_suggestions(cx);
+        cx.emit(InputAutoCompleteEvent::ValueChanged(self.value.clone()));
+        cx.notify();
+    }
+}
+
+impl Render for InputAutoComplete {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+
+        let mut field = div()
+            .id("input-autocomplete-field")
+            .flex()
+            .items_center()
+            .h_8()
+            .w_48()
+            .px_2()
+            .border_1()
+            .rounded_md()
+            .border_color(if focused {
+                rgb(0x0055ff)
+            } else {
+                rgb(0xcccccc)
+            })
+            .cursor_text()
+            .on_click(cx.listener(|this, _event, cx| cx.focus(&this.focus_handle)));
+
+        // Show the placeholder in gray while there is no value
+        if self.value.is_empty() {
+            field = field.child(
+                div()
+                    .text_color(rgb(0x999999))
+                    .child(self.placeholder.clone()),
+            );
+        } else {
+            field = field.child(self.value.clone());
+        }
+
+        // Text cursor is always at the end of the value
+        if focused {
+            field = field.child(div().w_px().h_4().bg(rgb(0x000000)));
+        }
+
+        let mut element = div()
+            .id("input-autocomplete")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .relative()
+            .m_1()
+            .child(field);
+
+        if self.open {
+            let items = self.suggestions.iter().enumerate().map(|(ix, suggestion)| {
+                let value = suggestion.clone();
+                let mut item = div()
+                    .id(ix)
+                    .px_2()
+                    .py_1()
+                    .cursor_pointer()
+                    .hover(|style| style.bg(rgb(0xeeeeee)))
+                    .child(suggestion.clone())
+                    .on_click(cx.listener(move |this, _event, cx| this.select(value.clone(), cx)));
+                if self.highlighted == Some(ix) {
+                    item = item.bg(rgb(0xdbeafe));
+                }
+                item
+            });
+
+            // Right below the field (h_8), on top of the following siblings
+            element = element.child(
+                div()
+                    .absolute()
+                    .top_8()
+                    .left_0()
+                    .mt_1()
+                    .flex()
+                    .flex_col()
+                    .w_48()
+                    .bg(rgb(0xffffff))
+                    .border_1()
+                    .border_color(rgb(0xcccccc))
+                    .rounded_md()
+                    .shadow_md()
+                    .children(items),
+            );
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for InputAutoComplete {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    // Records the queries the provider is asked for
+    fn autocomplete(
+        cx: &mut TestAppContext,
+    ) -> (
+        View<InputAutoComplete>,
+        &mut VisualTestContext,
+        Arc<Mutex<Vec<String>>>,
+    ) {
+        let queries = Arc::new(Mutex::new(Vec::new()));
+        let provider: SuggestionsProvider = {
+            let queries = queries.clone();
+            Arc::new(move |query| {
+                queries.lock().unwrap().push(query.to_string());
+                ["Deep sleep", "Online deep sleep", "Sleep"]
+                    .into_iter()
+                    .filter(|value| value.to_lowercase().contains(&query.to_lowercase()))
+                    .map(String::from)
+                    .collect()
+            })
+        };
+        let (view, cx) =
+            cx.add_window_view(|cx| InputAutoComplete::new(cx).suggestions_provider(provider));
+        (view, cx, queries)
+    }
+
+    fn press(view: &View<InputAutoComplete>, key: &str, cx: &mut VisualTestContext) {
+        let event = KeyDownEvent {
+            keystroke: Keystroke::parse(key).unwrap(),
+            is_held: false,
+        };
+        view.update(cx, |view, cx| view.handle_key_down(&event, cx));
+    }
+
+    #[gpui::test]
+    fn rapid_typing_asks_the_provider_once(cx: &mut TestAppContext) {
+        let (view, cx, queries) = autocomplete(cx);
+
+        for key in ["d", "e", "e", "p"] {
+            press(&view, key, cx);
+            cx.executor().advance_clock(Duration::from_millis(100));
+            cx.run_until_parked();
+        }
+        assert!(queries.lock().unwrap().is_empty());
+
+        cx.executor().advance_clock(DEBOUNCE);
+        cx.run_until_parked();
+        assert_eq!(*queries.lock().unwrap(), ["deep"]);
+        let (open, suggestions) = view.update(cx, |view, _| (view.open, view.suggestions.clone()));
+        assert!(open);
+        assert_eq!(suggestions, ["Deep sleep", "Online deep sleep"]);
+    }
+
+    #[gpui::test]
+    fn selecting_emits_selected(cx: &mut TestAppContext) {
+        let (view, cx, _queries) = autocomplete(cx);
+        let selected = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let selected = selected.clone();
+            cx.subscribe(&view, move |_, event: &InputAutoCompleteEvent, _| {
+                if let InputAutoCompleteEvent::Selected(value) = event {
+                    selected.borrow_mut().push(value.clone());
+                }
+            })
+            .detach();
+        });
+
+        press(&view, "s", cx);
+        cx.executor().advance_clock(DEBOUNCE);
+        cx.run_until_parked();
+        // Second suggestion with the arrow keys
+        for key in ["down", "down", "enter"] {
+            press(&view, key, cx);
+        }
+
+        assert_eq!(*selected.borrow(), ["Online deep sleep"]);
+        let (text, open) = view.update(cx, |view, _| (view.text().to_string(), view.open));
+        assert_eq!(text, "Online deep sleep");
+        assert!(!open);
+    }
+}
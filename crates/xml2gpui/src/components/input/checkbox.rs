@@ -1,22 +1,107 @@
 use gpui::*;
 
-#[derive(Clone, IntoElement)]
-pub struct InputCheckbox {}
+pub enum CheckboxEvent {
+    Changed(bool),
+}
+
+pub struct InputCheckbox {
+    checked: bool,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<CheckboxEvent> for InputCheckbox {}
 
 impl InputCheckbox {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            checked: false,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    fn toggle(&mut self, cx: &mut ViewContext<Self>) {
+        self.checked = !self.checked;
+        cx.emit(CheckboxEvent::Changed(self.checked));
+        cx.notify();
     }
 }
 
-impl RenderOnce for InputCheckbox {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        div().h_10().w_20().m_1().bg(rgb(0x0000ff))
+impl Render for InputCheckbox {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let mut element = div()
+            .id("checkbox")
+            .flex()
+            .items_center()
+            .justify_center()
+            .size_5()
+            .m_1()
+            .border_1()
+            .rounded_sm()
+            .border_color(rgb(0x0000ff))
+            .cursor_pointer()
+            .on_click(cx.listener(|this, _event, cx| this.toggle(cx)));
+
+        // Checked state is a filled box with a checkmark, unchecked is an empty box
+        if self.checked {
+            element = element.bg(rgb(0x0000ff)).child(
+                svg()
+                    .path("icons/check.svg")
+                    .size_4()
+                    .text_color(rgb(0xffffff)),
+            );
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
     }
 }
 
 impl Styled for InputCheckbox {
-    fn style(&mut self) -> &mut gpui::StyleRefinement {
-        self.style()
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn click_toggles_and_emits_changed(cx: &mut TestAppContext) {
+        let (checkbox, cx) = cx.add_window_view(|_cx| InputCheckbox::new());
+        let events = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(&checkbox, move |_, event: &CheckboxEvent, _| {
+                let CheckboxEvent::Changed(checked) = event;
+                events.borrow_mut().push(*checked);
+            })
+            .detach();
+        });
+
+        // What the on_click listener calls
+        checkbox.update(cx, |checkbox, cx| checkbox.toggle(cx));
+        assert!(checkbox.update(cx, |checkbox, _| checkbox.is_checked()));
+        checkbox.update(cx, |checkbox, cx| checkbox.toggle(cx));
+        assert!(!checkbox.update(cx, |checkbox, _| checkbox.is_checked()));
+        assert_eq!(*events.borrow(), [true, false]);
+    }
+
+    #[gpui::test]
+    fn checked_attribute(cx: &mut TestAppContext) {
+        let (checkbox, cx) = cx.add_window_view(|_cx| InputCheckbox::new().checked(true));
+        assert!(checkbox.update(cx, |checkbox, _| checkbox.is_checked()));
     }
 }
@@ -1,4 +1,8 @@
+pub mod autocomplete;
 pub mod checkbox;
 pub mod number;
+pub mod range;
 pub mod select;
+pub mod tag_list;
 pub mod text;
+pub mod toggle;
@@ -1,22 +1,217 @@
 use gpui::*;
 
-#[derive(Clone, IntoElement)]
-pub struct InputNumber {}
+pub enum InputNumberEvent {
+    // Always in the internal unit
+    ValueChanged(f64),
+}
+
+// Conversion between the unit the device uses and the unit shown to the user.
+// internal value = display value * factor, e.g. km/h -> m/s has factor 0.277778
+#[derive(Clone, Debug)]
+pub struct UnitConversion {
+    pub display_unit: SharedString,
+    pub internal_unit: SharedString,
+    pub factor: f64,
+}
+
+impl UnitConversion {
+    pub fn new(
+        display_unit: impl Into<SharedString>,
+        internal_unit: impl Into<SharedString>,
+        factor: f64,
+    ) -> Self {
+        Self {
+            display_unit: display_unit.into(),
+            internal_unit: internal_unit.into(),
+            factor,
+        }
+    }
+
+    pub fn to_display(&self, internal: f64) -> f64 {
+        internal / self.factor
+    }
+
+    pub fn to_internal(&self, display: f64) -> f64 {
+        display * self.factor
+    }
+}
+
+pub struct InputNumber {
+    // Value and limits are kept in the internal unit
+    value: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    unit: Option<UnitConversion>,
+    // What the user is typing, in the display unit
+    text: String,
+    focus_handle: FocusHandle,
+    style: StyleRefinement,
+    _blur_subscription: Subscription,
+}
+
+impl EventEmitter<InputNumberEvent> for InputNumber {}
 
 impl InputNumber {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        // Typed value is committed when focus moves somewhere else
+        let blur_subscription = cx.on_blur(&focus_handle, |this, cx| this.commit(cx));
+
+        Self {
+            value: 0.0,
+            min: None,
+            max: None,
+            unit: None,
+            text: String::from("0"),
+            focus_handle,
+            style: StyleRefinement::default(),
+            _blur_subscription: blur_subscription,
+        }
+    }
+
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self.text = self.format_display(value);
+        self
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn unit(mut self, unit: UnitConversion) -> Self {
+        self.unit = Some(unit);
+        self.text = self.format_display(self.value);
+        self
+    }
+
+    // Value in the internal unit
+    pub fn number(&self) -> f64 {
+        self.value
+    }
+
+    fn format_display(&self, internal: f64) -> String {
+        let display = match &self.unit {
+            Some(unit) => unit.to_display(internal),
+            None => internal,
+        };
+        // Round off the noise from the conversion, e.g. 99.99999 km/h
+        let rounded = (display * 1000.0).round() / 1000.0;
+        rounded.to_string()
+    }
+
+    fn commit(&mut self, cx: &mut ViewContext<Self>) {
+        let Ok(display) = self.text.trim().parse::<f64>() else {
+            // Not a number, go back to the last valid value
+            self.text = self.format_display(self.value);
+            cx.notify();
+            return;
+        };
+
+        // Limits are in the internal unit, so convert back before comparing
+        let mut internal = match &self.unit {
+            Some(unit) => unit.to_internal(display),
+            None => display,
+        };
+        if let Some(min) = self.min {
+            internal = internal.max(min);
+        }
+        if let Some(max) = self.max {
+            internal = internal.min(max);
+        }
+
+        let changed = internal != self.value;
+        self.value = internal;
+        self.text = self.format_display(internal);
+        if changed {
+            cx.emit(InputNumberEvent::ValueChanged(internal));
+        }
+        cx.notify();
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+
+        // Leave shortcuts to the application
+        if keystroke.modifiers.control || keystroke.modifiers.command {
+            return;
+        }
+
+        match keystroke.key.as_str() {
+            "backspace" => {
+                if self.text.pop().is_none() {
+                    return;
+                }
+            }
+            "enter" => return self.commit(cx),
+            key => {
+                let typed = keystroke.ime_key.as_deref().unwrap_or(key);
+                if !typed
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == '.' || c == '-')
+                {
+                    return;
+                }
+                self.text.push_str(typed);
+            }
+        }
+
+        cx.notify();
     }
 }
 
-impl RenderOnce for InputNumber {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        div().h_10().w_20().m_1().bg(rgb(0x0000ff))
+impl Render for InputNumber {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+
+        let mut element = div()
+            .id("input-number")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .on_click(cx.listener(|this, _event, cx| cx.focus(&this.focus_handle)))
+            .flex()
+            .items_center()
+            .gap_1()
+            .h_8()
+            .w_24()
+            .px_2()
+            .m_1()
+            .border_1()
+            .rounded_md()
+            .border_color(if focused {
+                rgb(0x0055ff)
+            } else {
+                rgb(0xcccccc)
+            })
+            .cursor_text()
+            .child(self.text.clone());
+
+        if focused {
+            element = element.child(div().w_px().h_4().bg(rgb(0x000000)));
+        }
+
+        if let Some(unit) = &self.unit {
+            element = element.child(
+                div()
+                    .text_color(rgb(0x999999))
+                    .child(unit.display_unit.clone()),
+            );
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
     }
 }
 
 impl Styled for InputNumber {
-    fn style(&mut self) -> &mut gpui::StyleRefinement {
-        self.style()
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
     }
 }
@@ -0,0 +1,383 @@
+use gpui::*;
+use std::{cell::Cell, rc::Rc};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Handle {
+    Min,
+    Max,
+}
+
+pub enum InputRangeEvent {
+    Changed { value_min: f64, value_max: f64 },
+    // Single value slider
+    ValueChanged(f64),
+}
+
+// Range input with two handles on one track for selecting a min/max pair, or a slider with one
+// handle when created with value()
+pub struct InputRange {
+    min: f64,
+    max: f64,
+    value_min: f64,
+    // The value of a single value slider
+    value_max: f64,
+    single: bool,
+    step: Option<f64>,
+    binding: Option<(Model<String>, Subscription)>,
+    dragging: Option<Handle>,
+    // Track bounds from the last paint, needed to map the mouse position to a value
+    track_bounds: Rc<Cell<Bounds<Pixels>>>,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<InputRangeEvent> for InputRange {}
+
+impl InputRange {
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            value_min: min,
+            value_max: max,
+            single: false,
+            step: None,
+            binding: None,
+            dragging: None,
+            track_bounds: Rc::new(Cell::new(Bounds::default())),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn value_min(mut self, value: f64) -> Self {
+        self.value_min = value.clamp(self.min, self.max);
+        self.value_max = self.value_max.max(self.value_min);
+        self
+    }
+
+    pub fn value_max(mut self, value: f64) -> Self {
+        self.value_max = value.clamp(self.min, self.max);
+        self.value_min = self.value_min.min(self.value_max);
+        self
+    }
+
+    // Makes this a single value slider, filled from min to the value
+    pub fn value(mut self, value: f64) -> Self {
+        self.single = true;
+        self.value_min = self.min;
+        self.value_max = self.snap(value);
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        if step > 0.0 {
+            self.step = Some(step);
+        }
+        self
+    }
+
+    // Single value slider shows the model's value and writes the dragged value back to it
+    pub fn bind(mut self, model: Model<String>, cx: &mut ViewContext<Self>) -> Self {
+        if let Ok(value) = model.read(cx).trim().parse::<f64>() {
+            self.value_max = self.snap(value);
+        }
+        let subscription = cx.observe(&model, |this, model, cx| {
+            if let Ok(value) = model.read(cx).trim().parse::<f64>() {
+                let value = this.snap(value);
+                if value != this.value_max {
+                    this.value_max = value;
+                    cx.notify();
+                }
+            }
+        });
+        self.binding = Some((model, subscription));
+        self
+    }
+
+    pub fn values(&self) -> (f64, f64) {
+        (self.value_min, self.value_max)
+    }
+
+    // Clamps to min..max and rounds to the nearest step from min
+    fn snap(&self, value: f64) -> f64 {
+        let value = value.clamp(self.min, self.max);
+        match self.step {
+            Some(step) => {
+                (self.min + ((value - self.min) / step).round() * step).clamp(self.min, self.max)
+            }
+            None => value,
+        }
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        // As many decimals as the step has, e.g. 0.25 -> 2
+        let decimals = match self.step {
+            Some(step) => step
+                .to_string()
+                .split_once('.')
+                .map_or(0, |(_, fraction)| fraction.len()),
+            None => 1,
+        };
+        format!("{:.*}", decimals, value)
+    }
+
+    // Position on the track between 0 and 1
+    fn fraction(&self, value: f64) -> f32 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)) as f32
+        } else {
+            0.0
+        }
+    }
+
+    fn drag_to(&mut self, position: Point<Pixels>, cx: &mut ViewContext<Self>) {
+        let Some(handle) = self.dragging else {
+            return;
+        };
+
+        let bounds = self.track_bounds.get();
+        if bounds.size.width <= px(0.0) {
+            return;
+        }
+        let fraction = ((position.x - bounds.origin.x) / bounds.size.width).clamp(0.0, 1.0) as f64;
+        let value = self.snap(self.min + fraction * (self.max - self.min));
+
+        // Handles can't pass each other
+        match handle {
+            Handle::Min => self.value_min = value.min(self.value_max),
+            Handle::Max => self.value_max = value.max(self.value_min),
+        }
+        cx.notify();
+    }
+
+    fn end_drag(&mut self, cx: &mut ViewContext<Self>) {
+        if self.dragging.take().is_none() {
+            return;
+        }
+
+        if self.single {
+            if let Some((model, _)) = &self.binding {
+                let value = self.format_value(self.value_max);
+                model.update(cx, |model, cx| {
+                    *model = value;
+                    cx.notify();
+                });
+            }
+            cx.emit(InputRangeEvent::ValueChanged(self.value_max));
+        } else {
+            cx.emit(InputRangeEvent::Changed {
+                value_min: self.value_min,
+                value_max: self.value_max,
+            });
+        }
+        cx.notify();
+    }
+
+    fn render_handle(&self, handle: Handle, value: f64, cx: &mut ViewContext<Self>) -> Div {
+        div()
+            .absolute()
+            .top(px(-4.0))
+            .left(relative(self.fraction(value)))
+            .ml(px(-8.0))
+            .size_4()
+            .rounded_full()
+            .bg(rgb(0xffffff))
+            .border_2()
+            .border_color(rgb(0x0055ff))
+            .cursor_grab()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _event, cx| {
+                    this.dragging = Some(handle);
+                    cx.notify();
+                }),
+            )
+    }
+}
+
+impl Render for InputRange {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let track_bounds = self.track_bounds.clone();
+        let start = self.fraction(self.value_min);
+        let end = self.fraction(self.value_max);
+
+        let track = div()
+            .relative()
+            .w_full()
+            .h_2()
+            .rounded_full()
+            .bg(rgb(0xcccccc))
+            // Selected part between the handles
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .h_full()
+                    .left(relative(start))
+                    .w(relative(end - start))
+                    .bg(rgb(0x0055ff)),
+            )
+            .child(
+                canvas(move |bounds, _cx| track_bounds.set(*bounds))
+                    .absolute()
+                    .size_full(),
+            )
+            .child(self.render_handle(Handle::Max, self.value_max, cx));
+        let track = if self.single {
+            track
+        } else {
+            track.child(self.render_handle(Handle::Min, self.value_min, cx))
+        };
+
+        let label = if self.single {
+            self.format_value(self.value_max)
+        } else {
+            format!(
+                "{} – {}",
+                self.format_value(self.value_min),
+                self.format_value(self.value_max)
+            )
+        };
+
+        let mut element = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .w_64()
+            .h_8()
+            .m_1()
+            .px_2()
+            .on_mouse_move(
+                cx.listener(|this, event: &MouseMoveEvent, cx| this.drag_to(event.position, cx)),
+            )
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _event, cx| this.end_drag(cx)),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _event, cx| this.end_drag(cx)),
+            )
+            .child(track)
+            .child(div().flex_none().child(label));
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for InputRange {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn record_values(
+        range: &View<InputRange>,
+        cx: &mut VisualTestContext,
+    ) -> Rc<RefCell<Vec<f64>>> {
+        let values = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let values = values.clone();
+            cx.subscribe(range, move |_, event: &InputRangeEvent, _| {
+                if let InputRangeEvent::ValueChanged(value) = event {
+                    values.borrow_mut().push(*value);
+                }
+            })
+            .detach();
+        });
+        values
+    }
+
+    // Drags the handle to a fraction of a 100px wide track
+    fn drag(range: &View<InputRange>, fraction: f32, cx: &mut VisualTestContext) {
+        range.update(cx, |range, cx| {
+            range.track_bounds.set(Bounds {
+                origin: point(px(0.0), px(0.0)),
+                size: size(px(100.0), px(8.0)),
+            });
+            range.dragging = Some(Handle::Max);
+            range.drag_to(point(px(100.0 * fraction), px(4.0)), cx);
+            range.end_drag(cx);
+        });
+    }
+
+    #[test]
+    fn value_is_clamped() {
+        let range = InputRange::new(1.0, 3600.0);
+        assert_eq!(range.snap(0.0), 1.0);
+        assert_eq!(range.snap(5000.0), 3600.0);
+        assert_eq!(range.snap(60.5), 60.5);
+        assert_eq!(InputRange::new(1.0, 3600.0).value(-10.0).values().1, 1.0);
+    }
+
+    #[test]
+    fn value_snaps_to_step_from_min() {
+        let range = InputRange::new(1.0, 3600.0).step(10.0);
+        assert_eq!(range.snap(59.0), 61.0);
+        assert_eq!(range.snap(64.0), 61.0);
+        assert_eq!(range.snap(67.0), 71.0);
+        // The last step past max is clamped
+        assert_eq!(range.snap(3599.0), 3600.0);
+
+        let range = InputRange::new(0.0, 1.0).step(0.25);
+        assert_eq!(range.snap(0.3), 0.25);
+        assert_eq!(range.format_value(0.25), "0.25");
+    }
+
+    #[test]
+    fn zero_step_is_ignored() {
+        let range = InputRange::new(0.0, 10.0).step(0.0);
+        assert_eq!(range.snap(3.3), 3.3);
+    }
+
+    #[test]
+    fn min_and_max_handles_dont_cross() {
+        let range = InputRange::new(0.0, 100.0).value_min(70.0).value_max(40.0);
+        assert_eq!(range.values(), (40.0, 40.0));
+    }
+
+    #[gpui::test]
+    fn dragging_emits_the_snapped_value(cx: &mut TestAppContext) {
+        let (range, cx) =
+            cx.add_window_view(|_cx| InputRange::new(0.0, 100.0).step(5.0).value(60.0));
+        let values = record_values(&range, cx);
+
+        drag(&range, 0.42, cx);
+        assert_eq!(*values.borrow(), [40.0]);
+        // Past the end of the track
+        drag(&range, 1.5, cx);
+        assert_eq!(*values.borrow(), [40.0, 100.0]);
+    }
+
+    #[gpui::test]
+    fn dragging_writes_the_bound_model(cx: &mut TestAppContext) {
+        let model = cx.new_model(|_| String::from("60"));
+        let (range, cx) = cx.add_window_view(|cx| {
+            InputRange::new(1.0, 3600.0)
+                .step(1.0)
+                .value(1.0)
+                .bind(model.clone(), cx)
+        });
+        assert_eq!(range.update(cx, |range, _| range.values().1), 60.0);
+
+        // 900.75 snaps to the step from min
+        drag(&range, 0.25, cx);
+        let value = range.update(cx, |range, _| range.values().1);
+        assert_eq!(value, 901.0);
+        assert_eq!(model.update(cx, |model, _| model.clone()), "901");
+
+        // Changes of the model move the handle
+        model.update(cx, |model, cx| {
+            *model = "120".into();
+            cx.notify();
+        });
+        cx.run_until_parked();
+        assert_eq!(range.update(cx, |range, _| range.values().1), 120.0);
+    }
+}
@@ -1,22 +1,235 @@
 use gpui::*;
 
-#[derive(Clone, IntoElement)]
-pub struct InputSelect {}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectOption {
+    pub value: String,
+    pub label: String,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+// Parses the options attribute format "0:Disable,1:Enable". Labels may contain commas
+// ("7:Galileo, Glonass and Beidou"), so a new option starts only from a piece that
+// has a "value:" prefix without whitespace
+pub fn parse_options(options: &str) -> Vec<SelectOption> {
+    let mut parsed: Vec<SelectOption> = Vec::new();
+    for piece in options.split(',') {
+        match piece.split_once(':') {
+            Some((value, label)) if !value.is_empty() && !value.contains(char::is_whitespace) => {
+                parsed.push(SelectOption::new(value, label));
+            }
+            _ => {
+                if let Some(last) = parsed.last_mut() {
+                    last.label.push(',');
+                    last.label.push_str(piece);
+                }
+            }
+        }
+    }
+    parsed
+}
+
+pub enum InputSelectEvent {
+    Selected(String),
+}
+
+pub struct InputSelect {
+    options: Vec<SelectOption>,
+    selected: Option<String>,
+    open: bool,
+    focus_handle: FocusHandle,
+    style: StyleRefinement,
+    _blur_subscription: Subscription,
+}
+
+impl EventEmitter<InputSelectEvent> for InputSelect {}
 
 impl InputSelect {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        // Close the popup when focus moves somewhere else
+        let blur_subscription = cx.on_blur(&focus_handle, |this, cx| {
+            this.open = false;
+            cx.notify();
+        });
+
+        Self {
+            options: Vec::new(),
+            selected: None,
+            open: false,
+            focus_handle,
+            style: StyleRefinement::default(),
+            _blur_subscription: blur_subscription,
+        }
+    }
+
+    pub fn options(mut self, options: Vec<SelectOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn selected(mut self, value: impl Into<String>) -> Self {
+        self.selected = Some(value.into());
+        self
+    }
+
+    // For options that change after the view is created
+    pub fn set_options(&mut self, options: Vec<SelectOption>, cx: &mut ViewContext<Self>) {
+        if options != self.options {
+            self.options = options;
+            cx.notify();
+        }
+    }
+
+    pub fn selected_value(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    fn select(&mut self, value: String, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        self.selected = Some(value.clone());
+        cx.emit(InputSelectEvent::Selected(value));
+        cx.notify();
     }
 }
 
-impl RenderOnce for InputSelect {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        div().h_10().w_20().m_1().bg(rgb(0x00ffff))
+impl Render for InputSelect {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let selected_label = self
+            .selected
+            .as_ref()
+            .and_then(|value| self.options.iter().find(|option| &option.value == value))
+            .map(|option| option.label.clone())
+            .unwrap_or_default();
+
+        let trigger = div()
+            .id("input-select-trigger")
+            .flex()
+            .items_center()
+            .justify_between()
+            .h_8()
+            .w_48()
+            .px_2()
+            .border_1()
+            .rounded_md()
+            .border_color(rgb(0xcccccc))
+            .cursor_pointer()
+            .child(selected_label)
+            .child(
+                svg()
+                    .path("icons/chevron-down.svg")
+                    .size_4()
+                    .text_color(rgb(0x666666)),
+            )
+            .on_click(cx.listener(|this, _event, cx| {
+                this.open = !this.open;
+                cx.focus(&this.focus_handle);
+                cx.notify();
+            }));
+
+        let mut element = div()
+            .id("input-select")
+            .track_focus(&self.focus_handle)
+            .flex()
+            .flex_col()
+            .m_1()
+            .child(trigger);
+
+        if self.open {
+            let items = self.options.iter().enumerate().map(|(ix, option)| {
+                let value = option.value.clone();
+                div()
+                    .id(ix)
+                    .px_2()
+                    .py_1()
+                    .cursor_pointer()
+                    .hover(|style| style.bg(rgb(0xeeeeee)))
+                    .child(option.label.clone())
+                    .on_click(cx.listener(move |this, _event, cx| this.select(value.clone(), cx)))
+            });
+
+            // Overlay draws the popup on top of the following siblings
+            element = element.child(
+                overlay().child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .w_48()
+                        .bg(rgb(0xffffff))
+                        .border_1()
+                        .border_color(rgb(0xcccccc))
+                        .rounded_md()
+                        .shadow_md()
+                        .children(items),
+                ),
+            );
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
     }
 }
 
 impl Styled for InputSelect {
-    fn style(&mut self) -> &mut gpui::StyleRefinement {
-        self.style()
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn select_emits_selected(cx: &mut TestAppContext) {
+        let (select, cx) = cx.add_window_view(|cx| {
+            InputSelect::new(cx).options(parse_options("0:Disable,1:Enable"))
+        });
+        let selected = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let selected = selected.clone();
+            cx.subscribe(&select, move |_, event: &InputSelectEvent, _| {
+                let InputSelectEvent::Selected(value) = event;
+                selected.borrow_mut().push(value.clone());
+            })
+            .detach();
+        });
+
+        // What clicking an option does
+        select.update(cx, |select, cx| select.select("1".into(), cx));
+        assert_eq!(*selected.borrow(), ["1"]);
+        let value = select.update(cx, |select, _| select.selected_value().map(String::from));
+        assert_eq!(value.as_deref(), Some("1"));
+    }
+
+    #[gpui::test]
+    fn set_options_keeps_the_selection(cx: &mut TestAppContext) {
+        let (select, cx) = cx.add_window_view(|cx| {
+            InputSelect::new(cx)
+                .options(parse_options("0:Disable,1:Enable"))
+                .selected("1")
+        });
+
+        select.update(cx, |select, cx| {
+            select.set_options(parse_options("0:Disable,1:Enable,2:Enable on ignition"), cx)
+        });
+        let (count, value) = select.update(cx, |select, _| {
+            (
+                select.options.len(),
+                select.selected_value().map(String::from),
+            )
+        });
+        assert_eq!(count, 3);
+        assert_eq!(value.as_deref(), Some("1"));
     }
 }
@@ -0,0 +1,215 @@
+use gpui::*;
+use regex::Regex;
+use std::time::Duration;
+
+pub enum InputTagListEvent {
+    Changed(Vec<String>),
+}
+
+// Horizontal offsets (px) the input steps through when a tag is rejected
+const SHAKE_OFFSETS: [f32; 6] = [-6.0, 6.0, -4.0, 4.0, -2.0, 0.0];
+
+// List of values shown as pills, with an inline input that adds a tag on Enter or comma
+pub struct InputTagList {
+    tags: Vec<String>,
+    input: String,
+    min_tags: usize,
+    max_tags: Option<usize>,
+    // Every tag must match this
+    validator: Option<Regex>,
+    shake_offset: f32,
+    focus_handle: FocusHandle,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<InputTagListEvent> for InputTagList {}
+
+impl InputTagList {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            tags: Vec::new(),
+            input: String::new(),
+            min_tags: 0,
+            max_tags: None,
+            validator: None,
+            shake_offset: 0.0,
+            focus_handle: cx.focus_handle(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn min_tags(mut self, min_tags: usize) -> Self {
+        self.min_tags = min_tags;
+        self
+    }
+
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = Some(max_tags);
+        self
+    }
+
+    pub fn validator(mut self, validator: Regex) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    pub fn values(&self) -> &[String] {
+        &self.tags
+    }
+
+    fn add_tag(&mut self, cx: &mut ViewContext<Self>) {
+        let tag = self.input.trim().to_string();
+        if tag.is_empty() {
+            return;
+        }
+
+        let full = self.max_tags.is_some_and(|max| self.tags.len() >= max);
+        let invalid = self
+            .validator
+            .as_ref()
+            .is_some_and(|validator| !validator.is_match(&tag));
+        if full || invalid || self.tags.contains(&tag) {
+            self.shake(cx);
+            return;
+        }
+
+        self.tags.push(tag);
+        self.input.clear();
+        cx.emit(InputTagListEvent::Changed(self.tags.clone()));
+        cx.notify();
+    }
+
+    fn remove_tag(&mut self, index: usize, cx: &mut ViewContext<Self>) {
+        if self.tags.len() <= self.min_tags || index >= self.tags.len() {
+            self.shake(cx);
+            return;
+        }
+
+        self.tags.remove(index);
+        cx.emit(InputTagListEvent::Changed(self.tags.clone()));
+        cx.notify();
+    }
+
+    fn shake(&mut self, cx: &mut ViewContext<Self>) {
+        cx.spawn(|this, mut cx| async move {
+            for offset in SHAKE_OFFSETS {
+                cx.background_executor()
+                    .timer(Duration::from_millis(40))
+                    .await;
+                let updated = this.update(&mut cx, |this, cx| {
+                    this.shake_offset = offset;
+                    cx.notify();
+                });
+                // View is gone
+                if updated.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+
+        // Leave shortcuts to the application
+        if keystroke.modifiers.control || keystroke.modifiers.command {
+            return;
+        }
+
+        match keystroke.key.as_str() {
+            "enter" | "," => return self.add_tag(cx),
+            // Backspace on an empty input removes the last tag
+            "backspace" => {
+                if self.input.pop().is_none() && !self.tags.is_empty() {
+                    return self.remove_tag(self.tags.len() - 1, cx);
+                }
+            }
+            _ => match &keystroke.ime_key {
+                Some(typed) if typed == "," => return self.add_tag(cx),
+                Some(typed) => self.input.push_str(typed),
+                None if keystroke.key.chars().count() == 1 => self.input.push_str(&keystroke.key),
+                None => return,
+            },
+        }
+
+        cx.notify();
+    }
+}
+
+impl Render for InputTagList {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+
+        let mut element = div()
+            .id("input-tag-list")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .on_click(cx.listener(|this, _event, cx| cx.focus(&this.focus_handle)))
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .gap_1()
+            .min_h_8()
+            .w_64()
+            .p_1()
+            .m_1()
+            .border_1()
+            .rounded_md()
+            .border_color(if focused {
+                rgb(0x0055ff)
+            } else {
+                rgb(0xcccccc)
+            })
+            .cursor_text();
+
+        for (index, tag) in self.tags.iter().enumerate() {
+            element = element.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_2()
+                    .rounded_full()
+                    .bg(rgb(0xdbeafe))
+                    .text_color(rgb(0x1e40af))
+                    .child(tag.clone())
+                    .child(
+                        div()
+                            .id(("remove-tag", index))
+                            .cursor_pointer()
+                            .child("×")
+                            .on_click(
+                                cx.listener(move |this, _event, cx| this.remove_tag(index, cx)),
+                            ),
+                    ),
+            );
+        }
+
+        element = element.child(self.input.clone());
+        // Text cursor is always at the end of the input
+        if focused {
+            element = element.child(div().w_px().h_4().bg(rgb(0x000000)));
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+
+        // Shake runs after the XML classes, so they can't cancel it
+        if self.shake_offset != 0.0 {
+            element = element.relative().left(px(self.shake_offset));
+        }
+        element
+    }
+}
+
+impl Styled for InputTagList {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
@@ -1,22 +1,142 @@
 use gpui::*;
 
-#[derive(Clone, IntoElement)]
-pub struct InputText {}
+pub enum InputTextEvent {
+    ValueChanged(String),
+}
+
+pub struct InputText {
+    value: String,
+    placeholder: SharedString,
+    // Model the value is kept in sync with, both ways
+    binding: Option<(Model<String>, Subscription)>,
+    focus_handle: FocusHandle,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<InputTextEvent> for InputText {}
 
 impl InputText {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            value: String::new(),
+            placeholder: SharedString::default(),
+            binding: None,
+            focus_handle: cx.focus_handle(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    // Shows the model's value and writes typed changes back to it. Changes made to the model
+    // elsewhere (e.g. loading a saved config) show up in the input
+    pub fn bind(mut self, model: Model<String>, cx: &mut ViewContext<Self>) -> Self {
+        self.value = model.read(cx).clone();
+        let subscription = cx.observe(&model, |this, model, cx| {
+            let value = model.read(cx);
+            if *value != this.value {
+                this.value = value.clone();
+                cx.notify();
+            }
+        });
+        self.binding = Some((model, subscription));
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.value
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+
+        // Leave shortcuts to the application
+        if keystroke.modifiers.control || keystroke.modifiers.command {
+            return;
+        }
+
+        match keystroke.key.as_str() {
+            "backspace" => {
+                if self.value.pop().is_none() {
+                    return;
+                }
+            }
+            // ime_key has the actual typed character (shift etc. applied)
+            _ => match &keystroke.ime_key {
+                Some(typed) => self.value.push_str(typed),
+                None if keystroke.key.chars().count() == 1 => self.value.push_str(&keystroke.key),
+                None => return,
+            },
+        }
+
+        if let Some((model, _)) = &self.binding {
+            let value = self.value.clone();
+            model.update(cx, |model, cx| {
+                *model = value;
+                cx.notify();
+            });
+        }
+        cx.emit(InputTextEvent::ValueChanged(self.value.clone()));
+        cx.notify();
     }
 }
 
-impl RenderOnce for InputText {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        div().h_10().w_20().m_1().bg(rgb(0x0000ff))
+impl Render for InputText {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+
+        let mut element = div()
+            .id("input-text")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .on_click(cx.listener(|this, _event, cx| cx.focus(&this.focus_handle)))
+            .flex()
+            .items_center()
+            .h_8()
+            .w_48()
+            .px_2()
+            .m_1()
+            .border_1()
+            .rounded_md()
+            .border_color(if focused {
+                rgb(0x0055ff)
+            } else {
+                rgb(0xcccccc)
+            })
+            .cursor_text();
+
+        // Show the placeholder in gray while there is no value
+        if self.value.is_empty() {
+            element = element.child(
+                div()
+                    .text_color(rgb(0x999999))
+                    .child(self.placeholder.clone()),
+            );
+        } else {
+            element = element.child(self.value.clone());
+        }
+
+        // Text cursor is always at the end of the value
+        if focused {
+            element = element.child(div().w_px().h_4().bg(rgb(0x000000)));
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
     }
 }
 
 impl Styled for InputText {
-    fn style(&mut self) -> &mut gpui::StyleRefinement {
-        self.style()
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
     }
 }
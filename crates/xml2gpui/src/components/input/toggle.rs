@@ -0,0 +1,228 @@
+use gpui::*;
+use std::time::Duration;
+
+pub enum InputToggleEvent {
+    Changed(bool),
+}
+
+// Knob positions (0 = off, 1 = on) stepped through when the toggle slides
+const SLIDE_STEPS: usize = 6;
+
+// On/off switch, an alternative look for a checkbox
+pub struct InputToggle {
+    checked: bool,
+    disabled: bool,
+    // Knob position between off (0.0) and on (1.0), animated when the toggle changes
+    knob_position: f32,
+    binding: Option<(Model<String>, Subscription)>,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<InputToggleEvent> for InputToggle {}
+
+impl InputToggle {
+    pub fn new() -> Self {
+        Self {
+            checked: false,
+            disabled: false,
+            knob_position: 0.0,
+            binding: None,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self.knob_position = if checked { 1.0 } else { 0.0 };
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    // Bound models hold "1" or "0" like the device parameters, "true" and "false" are read too
+    pub fn bind(mut self, model: Model<String>, cx: &mut ViewContext<Self>) -> Self {
+        self = self.checked(is_on(model.read(cx)));
+        let subscription = cx.observe(&model, |this, model, cx| {
+            let checked = is_on(model.read(cx));
+            if checked != this.checked {
+                this.checked = checked;
+                this.slide(cx);
+            }
+        });
+        self.binding = Some((model, subscription));
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    fn toggle(&mut self, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        self.checked = !self.checked;
+        if let Some((model, _)) = &self.binding {
+            let value = if self.checked { "1" } else { "0" }.to_string();
+            model.update(cx, |model, cx| {
+                *model = value;
+                cx.notify();
+            });
+        }
+        cx.emit(InputToggleEvent::Changed(self.checked));
+        self.slide(cx);
+    }
+
+    // Moves the knob to the current state a step per frame
+    fn slide(&mut self, cx: &mut ViewContext<Self>) {
+        cx.spawn(|this, mut cx| async move {
+            for _ in 0..SLIDE_STEPS {
+                cx.background_executor()
+                    .timer(Duration::from_millis(16))
+                    .await;
+                let updated = this.update(&mut cx, |this, cx| {
+                    let target = if this.checked { 1.0 } else { 0.0 };
+                    let step = 1.0 / SLIDE_STEPS as f32;
+                    this.knob_position = if this.knob_position < target {
+                        (this.knob_position + step).min(target)
+                    } else {
+                        (this.knob_position - step).max(target)
+                    };
+                    cx.notify();
+                });
+                // View is gone
+                if updated.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+}
+
+impl Default for InputToggle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_on(value: &str) -> bool {
+    matches!(value.trim(), "1" | "true")
+}
+
+impl Render for InputToggle {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let track_color = match (self.disabled, self.checked) {
+            (true, _) => rgb(0xe5e7eb),
+            (false, true) => rgb(0x0055ff),
+            (false, false) => rgb(0xcccccc),
+        };
+
+        let mut element = div()
+            .id("toggle")
+            .relative()
+            .flex_none()
+            .w_11()
+            .h_6()
+            .m_1()
+            .rounded_full()
+            .bg(track_color)
+            .child(
+                div()
+                    .absolute()
+                    .top(px(2.0))
+                    // Knob is 20px on a 44px track, so it travels 20px between the 2px margins
+                    .left(px(2.0 + self.knob_position * 20.0))
+                    .size_5()
+                    .rounded_full()
+                    .bg(rgb(0xffffff))
+                    .shadow_sm(),
+            );
+
+        if self.disabled {
+            element = element.cursor_not_allowed();
+        } else {
+            element = element
+                .cursor_pointer()
+                .on_click(cx.listener(|this, _event, cx| this.toggle(cx)));
+        }
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for InputToggle {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn record_events(
+        toggle: &View<InputToggle>,
+        cx: &mut VisualTestContext,
+    ) -> Rc<RefCell<Vec<bool>>> {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(toggle, move |_, event: &InputToggleEvent, _| {
+                let InputToggleEvent::Changed(checked) = event;
+                events.borrow_mut().push(*checked);
+            })
+            .detach();
+        });
+        events
+    }
+
+    fn state(toggle: &View<InputToggle>, cx: &mut VisualTestContext) -> (bool, f32) {
+        toggle.update(cx, |toggle, _| (toggle.is_checked(), toggle.knob_position))
+    }
+
+    #[gpui::test]
+    fn initial_state(cx: &mut TestAppContext) {
+        let (off, off_cx) = cx.add_window_view(|_cx| InputToggle::new());
+        assert_eq!(state(&off, off_cx), (false, 0.0));
+        let (on, on_cx) = cx.add_window_view(|_cx| InputToggle::new().checked(true));
+        assert_eq!(state(&on, on_cx), (true, 1.0));
+    }
+
+    #[gpui::test]
+    fn toggle_emits_changed_and_slides(cx: &mut TestAppContext) {
+        let (toggle, cx) = cx.add_window_view(|_cx| InputToggle::new());
+        let events = record_events(&toggle, cx);
+
+        toggle.update(cx, |toggle, cx| toggle.toggle(cx));
+        assert_eq!(*events.borrow(), [true]);
+        // The knob gets to the other end after the slide steps
+        cx.executor()
+            .advance_clock(Duration::from_millis(16 * SLIDE_STEPS as u64));
+        cx.run_until_parked();
+        let (checked, knob_position) = state(&toggle, cx);
+        assert!(checked);
+        assert!((knob_position - 1.0).abs() < 1e-6);
+
+        toggle.update(cx, |toggle, cx| toggle.toggle(cx));
+        assert_eq!(*events.borrow(), [true, false]);
+    }
+
+    #[gpui::test]
+    fn disabled_blocks_toggling(cx: &mut TestAppContext) {
+        let (toggle, cx) = cx.add_window_view(|_cx| InputToggle::new().disabled(true));
+        let events = record_events(&toggle, cx);
+
+        toggle.update(cx, |toggle, cx| toggle.toggle(cx));
+        assert_eq!(state(&toggle, cx), (false, 0.0));
+        assert!(events.borrow().is_empty());
+    }
+}
@@ -1 +1,13 @@
+pub mod accordion;
+pub mod alert;
+pub mod breadcrumb;
+pub mod drawer;
+pub mod gradient;
 pub mod input;
+pub mod modal;
+pub mod pagination;
+pub mod progress;
+pub mod spinner;
+pub mod table;
+pub mod tabs;
+pub mod tooltip;
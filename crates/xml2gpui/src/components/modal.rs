@@ -0,0 +1,259 @@
+use gpui::*;
+
+use crate::handlers::Handler;
+
+pub enum ModalEvent {
+    Confirmed,
+    Cancelled,
+}
+
+// Confirmation dialog drawn over the whole window. Opened from the XML with
+// on:click="modal:<id>"
+pub struct Modal {
+    title: String,
+    body: String,
+    open: bool,
+    on_confirm: Option<Handler>,
+    on_cancel: Option<Handler>,
+    focus_handle: FocusHandle,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<ModalEvent> for Modal {}
+
+impl Modal {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            title: String::new(),
+            body: String::new(),
+            open: false,
+            on_confirm: None,
+            on_cancel: None,
+            focus_handle: cx.focus_handle(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn on_confirm(mut self, handler: Handler) -> Self {
+        self.on_confirm = Some(handler);
+        self
+    }
+
+    pub fn on_cancel(mut self, handler: Handler) -> Self {
+        self.on_cancel = Some(handler);
+        self
+    }
+
+    pub fn set_text(&mut self, title: String, body: String, cx: &mut ViewContext<Self>) {
+        if title != self.title || body != self.body {
+            self.title = title;
+            self.body = body;
+            cx.notify();
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = true;
+        cx.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    pub fn confirm(&mut self, cx: &mut ViewContext<Self>) {
+        self.close(ModalEvent::Confirmed, cx);
+    }
+
+    pub fn cancel(&mut self, cx: &mut ViewContext<Self>) {
+        self.close(ModalEvent::Cancelled, cx);
+    }
+
+    fn close(&mut self, event: ModalEvent, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        let handler = match event {
+            ModalEvent::Confirmed => self.on_confirm.clone(),
+            ModalEvent::Cancelled => self.on_cancel.clone(),
+        };
+        cx.emit(event);
+        if let Some(handler) = handler {
+            handler(cx);
+        }
+        cx.notify();
+    }
+}
+
+fn button(id: &'static str, label: &'static str) -> Stateful<Div> {
+    div()
+        .id(id)
+        .px_3()
+        .py_1()
+        .border_1()
+        .rounded_md()
+        .border_color(rgb(0xcccccc))
+        .cursor_pointer()
+        .child(label)
+}
+
+impl Render for Modal {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !self.open {
+            return div().into_any_element();
+        }
+
+        let mut dialog = div()
+            .id("modal")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(|this, event: &KeyDownEvent, cx| {
+                match event.keystroke.key.as_str() {
+                    "escape" => this.cancel(cx),
+                    "enter" => this.confirm(cx),
+                    _ => (),
+                }
+            }))
+            .flex()
+            .flex_col()
+            .gap_2()
+            .w_96()
+            .p_4()
+            .rounded_md()
+            .bg(rgb(0xffffff))
+            .shadow_lg()
+            .child(
+                div()
+                    .font_weight(FontWeight::BOLD)
+                    .child(self.title.clone()),
+            )
+            .child(self.body.clone())
+            .child(
+                div()
+                    .flex()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        button("modal-cancel", "Cancel")
+                            .on_click(cx.listener(|this, _event, cx| this.cancel(cx))),
+                    )
+                    .child(
+                        button("modal-confirm", "OK")
+                            .bg(rgb(0x0055ff))
+                            .text_color(rgb(0xffffff))
+                            .on_click(cx.listener(|this, _event, cx| this.confirm(cx))),
+                    ),
+            );
+        // Classes from the XML are applied on top of the default look
+        dialog.style().refine(&self.style);
+
+        // Overlay is positioned in window coordinates, so the backdrop covers the window wherever
+        // the <modal> element is in the tree
+        let viewport = cx.viewport_size();
+        overlay()
+            .position(point(px(0.0), px(0.0)))
+            .child(
+                div()
+                    .w(viewport.width)
+                    .h(viewport.height)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(hsla(0.0, 0.0, 0.0, 0.4))
+                    .child(dialog),
+            )
+            .into_any_element()
+    }
+}
+
+impl Styled for Modal {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug, PartialEq)]
+    enum Recorded {
+        Confirmed,
+        Cancelled,
+    }
+
+    fn record_events(
+        modal: &View<Modal>,
+        cx: &mut VisualTestContext,
+    ) -> Rc<RefCell<Vec<Recorded>>> {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(modal, move |_, event: &ModalEvent, _| {
+                events.borrow_mut().push(match event {
+                    ModalEvent::Confirmed => Recorded::Confirmed,
+                    ModalEvent::Cancelled => Recorded::Cancelled,
+                });
+            })
+            .detach();
+        });
+        events
+    }
+
+    #[gpui::test]
+    fn confirm_emits_confirmed_and_runs_the_handler(cx: &mut TestAppContext) {
+        let confirmed = Arc::new(AtomicUsize::new(0));
+        let handler: Handler = {
+            let confirmed = confirmed.clone();
+            Arc::new(move |_cx| {
+                confirmed.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        let (modal, cx) = cx.add_window_view(|cx| Modal::new(cx).on_confirm(handler));
+        let events = record_events(&modal, cx);
+
+        modal.update(cx, |modal, cx| modal.open(cx));
+        cx.run_until_parked();
+        assert!(modal.update(cx, |modal, _| modal.is_open()));
+
+        // What the OK button does
+        modal.update(cx, |modal, cx| modal.confirm(cx));
+        assert_eq!(*events.borrow(), [Recorded::Confirmed]);
+        assert_eq!(confirmed.load(Ordering::SeqCst), 1);
+        assert!(!modal.update(cx, |modal, _| modal.is_open()));
+    }
+
+    #[gpui::test]
+    fn cancel_emits_cancelled(cx: &mut TestAppContext) {
+        let (modal, cx) = cx.add_window_view(Modal::new);
+        let events = record_events(&modal, cx);
+
+        modal.update(cx, |modal, cx| modal.open(cx));
+        modal.update(cx, |modal, cx| modal.cancel(cx));
+        assert_eq!(*events.borrow(), [Recorded::Cancelled]);
+    }
+
+    #[gpui::test]
+    fn set_text_replaces_the_texts(cx: &mut TestAppContext) {
+        let (modal, cx) =
+            cx.add_window_view(|cx| Modal::new(cx).title("Save").body("Save 1 change?"));
+
+        modal.update(cx, |modal, cx| {
+            modal.set_text("Save".into(), "Save 2 changes?".into(), cx)
+        });
+        let texts = modal.update(cx, |modal, _| (modal.title.clone(), modal.body.clone()));
+        assert_eq!(texts, ("Save".into(), "Save 2 changes?".into()));
+    }
+}
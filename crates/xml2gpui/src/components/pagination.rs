@@ -0,0 +1,326 @@
+use gpui::*;
+
+use crate::handlers::Handler;
+
+pub enum PaginationEvent {
+    PageChanged(usize),
+}
+
+// Page buttons shown at most, ellipses included
+const MAX_BUTTONS: usize = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PageButton {
+    Page(usize),
+    Ellipsis,
+}
+
+// Row of page buttons between prev and next arrows. Pages start from 1
+pub struct Pagination {
+    total_items: usize,
+    page_size: usize,
+    current_page: usize,
+    // Only the arrows and "Page X of Y"
+    compact: bool,
+    on_change: Option<Handler>,
+    // Model the current page is written to, so the on:change handler can read it
+    binding: Option<Model<String>>,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<PaginationEvent> for Pagination {}
+
+impl Pagination {
+    pub fn new(total_items: usize, page_size: usize) -> Self {
+        Self {
+            total_items,
+            page_size,
+            current_page: 1,
+            compact: false,
+            on_change: None,
+            binding: None,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn current_page(mut self, page: usize) -> Self {
+        self.current_page = page.clamp(1, self.page_count());
+        self
+    }
+
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    pub fn on_change(mut self, handler: Handler) -> Self {
+        self.on_change = Some(handler);
+        self
+    }
+
+    pub fn bind(mut self, model: Model<String>) -> Self {
+        self.binding = Some(model);
+        self
+    }
+
+    pub fn page(&self) -> usize {
+        self.current_page
+    }
+
+    // Always at least one page, even when there are no items
+    pub fn page_count(&self) -> usize {
+        if self.page_size == 0 {
+            return 1;
+        }
+        self.total_items.div_ceil(self.page_size).max(1)
+    }
+
+    // The first and last pages are always shown, the pages around the current one fill the rest
+    pub fn buttons(&self) -> Vec<PageButton> {
+        let count = self.page_count();
+        if count <= MAX_BUTTONS {
+            return (1..=count).map(PageButton::Page).collect();
+        }
+
+        let current = self.current_page;
+        let middle: Vec<usize> = if current <= 4 {
+            (2..=5).collect()
+        } else if current >= count - 3 {
+            (count - 4..count).collect()
+        } else {
+            (current - 1..=current + 1).collect()
+        };
+
+        let mut buttons = vec![PageButton::Page(1)];
+        if middle[0] > 2 {
+            buttons.push(PageButton::Ellipsis);
+        }
+        buttons.extend(middle.iter().copied().map(PageButton::Page));
+        if middle[middle.len() - 1] < count - 1 {
+            buttons.push(PageButton::Ellipsis);
+        }
+        buttons.push(PageButton::Page(count));
+        buttons
+    }
+
+    // The total can change when the list is filtered, the current page stays in range
+    pub fn set_total_items(&mut self, total_items: usize, cx: &mut ViewContext<Self>) {
+        if total_items == self.total_items {
+            return;
+        }
+        self.total_items = total_items;
+        if self.current_page > self.page_count() {
+            self.go_to(self.page_count(), cx);
+        }
+        cx.notify();
+    }
+
+    pub fn go_to(&mut self, page: usize, cx: &mut ViewContext<Self>) {
+        let page = page.clamp(1, self.page_count());
+        if page == self.current_page {
+            return;
+        }
+
+        self.current_page = page;
+        if let Some(model) = &self.binding {
+            model.update(cx, |model, cx| {
+                *model = page.to_string();
+                cx.notify();
+            });
+        }
+        cx.emit(PaginationEvent::PageChanged(page));
+        if let Some(handler) = self.on_change.clone() {
+            // Pages can change while the parent is rendered, which is no place for the handler
+            // to update other views
+            cx.defer(move |_this, cx| handler(cx));
+        }
+        cx.notify();
+    }
+
+    fn arrow(
+        &self,
+        id: &'static str,
+        label: &'static str,
+        page: usize,
+        enabled: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> Stateful<Div> {
+        let element = div().id(id).px_2().py_1().rounded_md().child(label);
+        if enabled {
+            element
+                .cursor_pointer()
+                .hover(|style| style.bg(rgb(0xeeeeee)))
+                .on_click(cx.listener(move |this, _event, cx| this.go_to(page, cx)))
+        } else {
+            element.text_color(rgb(0xcccccc))
+        }
+    }
+}
+
+impl Render for Pagination {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let current = self.current_page;
+        let count = self.page_count();
+
+        let mut element = div().flex().items_center().gap_1().m_1().child(self.arrow(
+            "page-prev",
+            "‹",
+            current.saturating_sub(1),
+            current > 1,
+            cx,
+        ));
+
+        if self.compact {
+            element = element.child(div().px_2().child(format!("Page {} of {}", current, count)));
+        } else {
+            for (index, button) in self.buttons().into_iter().enumerate() {
+                element = element.child(match button {
+                    PageButton::Page(page) if page == current => div()
+                        .id(("page", index))
+                        .px_2()
+                        .py_1()
+                        .rounded_md()
+                        .bg(rgb(0x0055ff))
+                        .text_color(rgb(0xffffff))
+                        .child(page.to_string()),
+                    PageButton::Page(page) => div()
+                        .id(("page", index))
+                        .px_2()
+                        .py_1()
+                        .rounded_md()
+                        .cursor_pointer()
+                        .hover(|style| style.bg(rgb(0xeeeeee)))
+                        .child(page.to_string())
+                        .on_click(cx.listener(move |this, _event, cx| this.go_to(page, cx))),
+                    PageButton::Ellipsis => div()
+                        .id(("page", index))
+                        .px_1()
+                        .text_color(rgb(0x999999))
+                        .child("…"),
+                });
+            }
+        }
+
+        element = element.child(self.arrow("page-next", "›", current + 1, current < count, cx));
+
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for Pagination {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PageButton::{Ellipsis, Page};
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn page_count() {
+        assert_eq!(Pagination::new(0, 10).page_count(), 1);
+        assert_eq!(Pagination::new(25, 0).page_count(), 1);
+        assert_eq!(Pagination::new(10, 10).page_count(), 1);
+        assert_eq!(Pagination::new(11, 10).page_count(), 2);
+        assert_eq!(Pagination::new(200, 10).page_count(), 20);
+    }
+
+    #[test]
+    fn all_pages_fit() {
+        assert_eq!(Pagination::new(0, 10).buttons(), [Page(1)]);
+        assert_eq!(Pagination::new(5, 0).buttons(), [Page(1)]);
+        assert_eq!(
+            Pagination::new(70, 10).current_page(4).buttons(),
+            (1..=7).map(Page).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn current_page_near_the_start() {
+        assert_eq!(
+            Pagination::new(200, 10).current_page(1).buttons(),
+            [
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn current_page_in_the_middle() {
+        assert_eq!(
+            Pagination::new(200, 10).current_page(10).buttons(),
+            [
+                Page(1),
+                Ellipsis,
+                Page(9),
+                Page(10),
+                Page(11),
+                Ellipsis,
+                Page(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn current_page_near_the_end() {
+        assert_eq!(
+            Pagination::new(200, 10).current_page(20).buttons(),
+            [
+                Page(1),
+                Ellipsis,
+                Page(16),
+                Page(17),
+                Page(18),
+                Page(19),
+                Page(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn current_page_is_clamped() {
+        assert_eq!(Pagination::new(200, 10).current_page(0).page(), 1);
+        assert_eq!(Pagination::new(200, 10).current_page(50).page(), 20);
+        assert_eq!(Pagination::new(0, 10).current_page(3).page(), 1);
+    }
+
+    #[gpui::test]
+    fn go_to_emits_and_writes_the_bound_model(cx: &mut TestAppContext) {
+        let model = cx.new_model(|_| String::from("1"));
+        let (pagination, cx) =
+            cx.add_window_view(|_cx| Pagination::new(200, 10).bind(model.clone()));
+        let pages = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let pages = pages.clone();
+            cx.subscribe(&pagination, move |_, event: &PaginationEvent, _| {
+                let PaginationEvent::PageChanged(page) = event;
+                pages.borrow_mut().push(*page);
+            })
+            .detach();
+        });
+
+        pagination.update(cx, |pagination, cx| pagination.go_to(3, cx));
+        // Same page again and past the last page
+        pagination.update(cx, |pagination, cx| pagination.go_to(3, cx));
+        pagination.update(cx, |pagination, cx| pagination.go_to(99, cx));
+        assert_eq!(*pages.borrow(), [3, 20]);
+        assert_eq!(model.update(cx, |model, _| model.clone()), "20");
+
+        // Fewer items move the current page back into range
+        pagination.update(cx, |pagination, cx| pagination.set_total_items(45, cx));
+        assert_eq!(*pages.borrow(), [3, 20, 5]);
+        assert_eq!(pagination.update(cx, |pagination, _| pagination.page()), 5);
+    }
+}
@@ -0,0 +1,177 @@
+use gpui::*;
+
+use crate::handlers::Handler;
+
+pub enum ProgressBarEvent {
+    Complete,
+}
+
+// Horizontal bar filled by value / max
+pub struct ProgressBar {
+    value: f32,
+    max: f32,
+    color: Rgba,
+    on_complete: Option<Handler>,
+    style: StyleRefinement,
+}
+
+impl EventEmitter<ProgressBarEvent> for ProgressBar {}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressBar {
+    pub fn new() -> Self {
+        Self {
+            value: 0.0,
+            max: 100.0,
+            color: rgb(0x0055ff),
+            on_complete: None,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn on_complete(mut self, handler: Handler) -> Self {
+        self.on_complete = Some(handler);
+        self
+    }
+
+    // Filled part between 0 and 1
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        self.value.clamp(0.0, self.max) / self.max
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.max > 0.0 && self.value >= self.max
+    }
+
+    // Emits Complete when the value reaches max
+    pub fn set_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        if value == self.value {
+            return;
+        }
+
+        let was_complete = self.is_complete();
+        self.value = value;
+        if !was_complete && self.is_complete() {
+            // Value is usually set while the parent is rendered, which is no place for the
+            // handler to update other views
+            cx.defer(|this, cx| {
+                cx.emit(ProgressBarEvent::Complete);
+                if let Some(handler) = this.on_complete.clone() {
+                    handler(cx);
+                }
+            });
+        }
+        cx.notify();
+    }
+}
+
+impl Render for ProgressBar {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let mut element = div()
+            .w_full()
+            .h_2()
+            .m_1()
+            .rounded_full()
+            .overflow_hidden()
+            .bg(rgb(0xe5e7eb))
+            .child(
+                div()
+                    .h_full()
+                    .rounded_full()
+                    .bg(self.color)
+                    .w(relative(self.fraction())),
+            );
+        // Classes from the XML are applied to the track
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for ProgressBar {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn fraction_of_max() {
+        assert_eq!(ProgressBar::new().value(50.0).fraction(), 0.5);
+        assert_eq!(ProgressBar::new().value(3.0).max(4.0).fraction(), 0.75);
+    }
+
+    #[test]
+    fn fraction_is_clamped() {
+        assert_eq!(ProgressBar::new().value(-10.0).fraction(), 0.0);
+        assert_eq!(ProgressBar::new().value(150.0).fraction(), 1.0);
+    }
+
+    #[test]
+    fn no_progress_without_a_positive_max() {
+        for max in [0.0, -5.0] {
+            let progress = ProgressBar::new().value(10.0).max(max);
+            assert_eq!(progress.fraction(), 0.0);
+            assert!(!progress.is_complete());
+        }
+    }
+
+    #[test]
+    fn complete_at_max() {
+        assert!(!ProgressBar::new().value(99.9).is_complete());
+        assert!(ProgressBar::new().value(100.0).is_complete());
+        assert!(ProgressBar::new().value(120.0).is_complete());
+    }
+
+    #[gpui::test]
+    fn crossing_max_emits_complete_once(cx: &mut TestAppContext) {
+        let (progress, cx) = cx.add_window_view(|_cx| ProgressBar::new());
+        let completions = Rc::new(Cell::new(0));
+        cx.update(|cx| {
+            let completions = completions.clone();
+            cx.subscribe(&progress, move |_, _event: &ProgressBarEvent, _| {
+                completions.set(completions.get() + 1)
+            })
+            .detach();
+        });
+
+        for value in [40.0, 90.0, 100.0, 110.0, 100.0] {
+            progress.update(cx, |progress, cx| progress.set_value(value, cx));
+        }
+        cx.run_until_parked();
+        assert_eq!(completions.get(), 1);
+
+        // Going back below max and up again is another completion
+        progress.update(cx, |progress, cx| progress.set_value(0.0, cx));
+        progress.update(cx, |progress, cx| progress.set_value(100.0, cx));
+        cx.run_until_parked();
+        assert_eq!(completions.get(), 2);
+    }
+}
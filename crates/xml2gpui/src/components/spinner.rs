@@ -0,0 +1,148 @@
+use gpui::*;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SpinnerSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl SpinnerSize {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "sm" | "small" => Some(SpinnerSize::Small),
+            "md" | "medium" => Some(SpinnerSize::Medium),
+            "lg" | "large" => Some(SpinnerSize::Large),
+            _ => None,
+        }
+    }
+
+    fn pixels(self) -> Pixels {
+        match self {
+            SpinnerSize::Small => px(16.0),
+            SpinnerSize::Medium => px(24.0),
+            SpinnerSize::Large => px(40.0),
+        }
+    }
+}
+
+// About 60 frames per second, one turn per second
+const FRAME: Duration = Duration::from_millis(16);
+const TURN_PER_FRAME: f32 = std::f32::consts::TAU / 60.0;
+
+// Rotating arc for loading and saving
+pub struct Spinner {
+    size: SpinnerSize,
+    color: Rgba,
+    // Radians
+    angle: f32,
+    // Dropped with the view, which stops the rotation
+    _animation: Task<()>,
+    style: StyleRefinement,
+}
+
+impl Spinner {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let animation = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(FRAME).await;
+                let updated = this.update(&mut cx, |this, cx| {
+                    this.angle = (this.angle + TURN_PER_FRAME) % std::f32::consts::TAU;
+                    cx.notify();
+                });
+                // View is gone
+                if updated.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            size: SpinnerSize::default(),
+            color: rgb(0x0055ff),
+            angle: 0.0,
+            _animation: animation,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn size(mut self, size: SpinnerSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn angle(&self) -> f32 {
+        self.angle
+    }
+}
+
+impl Render for Spinner {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let mut element = svg()
+            .path("icons/loader-2.svg")
+            .size(self.size.pixels())
+            .flex_none()
+            .text_color(self.color)
+            .with_transformation(Transformation::rotate(radians(self.angle)));
+        // Classes from the XML are applied on top of the default look, e.g. text-color-[#hex]
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for Spinner {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn angle(spinner: &View<Spinner>, cx: &mut VisualTestContext) -> f32 {
+        spinner.update(cx, |spinner, _| spinner.angle())
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(SpinnerSize::parse("sm"), Some(SpinnerSize::Small));
+        assert_eq!(SpinnerSize::parse("medium"), Some(SpinnerSize::Medium));
+        assert_eq!(SpinnerSize::parse("lg"), Some(SpinnerSize::Large));
+        assert_eq!(SpinnerSize::parse("xl"), None);
+    }
+
+    #[gpui::test]
+    fn angle_advances_each_frame(cx: &mut TestAppContext) {
+        let (spinner, cx) = cx.add_window_view(Spinner::new);
+        cx.run_until_parked();
+        assert_eq!(angle(&spinner, cx), 0.0);
+
+        cx.executor().advance_clock(FRAME);
+        cx.run_until_parked();
+        assert!((angle(&spinner, cx) - TURN_PER_FRAME).abs() < 1e-6);
+
+        cx.executor().advance_clock(FRAME);
+        cx.run_until_parked();
+        assert!((angle(&spinner, cx) - 2.0 * TURN_PER_FRAME).abs() < 1e-6);
+    }
+
+    #[gpui::test]
+    fn angle_wraps_after_a_turn(cx: &mut TestAppContext) {
+        let (spinner, cx) = cx.add_window_view(Spinner::new);
+        for _ in 0..90 {
+            cx.executor().advance_clock(FRAME);
+            cx.run_until_parked();
+        }
+        let angle = angle(&spinner, cx);
+        assert!((0.0..std::f32::consts::TAU).contains(&angle));
+        assert!((angle - std::f32::consts::PI).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,153 @@
+use gpui::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnDef {
+    pub header: String,
+    // Columns without a width share the remaining space
+    pub width: Option<Length>,
+}
+
+impl ColumnDef {
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            width: None,
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+}
+
+// Read-only table with a header row and alternating row colors
+#[derive(IntoElement)]
+pub struct Table {
+    columns: Vec<ColumnDef>,
+    rows: Vec<Vec<String>>,
+    style: StyleRefinement,
+}
+
+impl Table {
+    pub fn new(columns: Vec<ColumnDef>, rows: Vec<Vec<String>>) -> Self {
+        Self {
+            columns,
+            rows,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn columns(&self) -> &[ColumnDef] {
+        &self.columns
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+}
+
+fn cell(column: &ColumnDef, text: String) -> Div {
+    let cell = div().px_2().py_1().overflow_hidden().child(text);
+    match column.width {
+        Some(width) => cell.flex_none().w(width),
+        None => cell.flex_1(),
+    }
+}
+
+impl RenderOnce for Table {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let Table {
+            columns,
+            rows,
+            style,
+        } = self;
+
+        let header = div()
+            .flex()
+            .border_b_1()
+            .border_color(rgb(0xcccccc))
+            .font_weight(FontWeight::BOLD)
+            .children(
+                columns
+                    .iter()
+                    .map(|column| cell(column, column.header.clone())),
+            );
+
+        // Rows are cut or padded to the number of columns
+        let rows = rows.into_iter().enumerate().map(|(index, row)| {
+            div()
+                .flex()
+                .bg(if index % 2 == 0 {
+                    rgb(0xffffff)
+                } else {
+                    rgb(0xf3f4f6)
+                })
+                .children(columns.iter().enumerate().map(|(column_index, column)| {
+                    cell(column, row.get(column_index).cloned().unwrap_or_default())
+                }))
+        });
+
+        let mut element = div().flex().flex_col().child(header).children(rows);
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&style);
+        element
+    }
+}
+
+impl Styled for Table {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        Table::new(
+            vec![
+                ColumnDef::new("Parameter").width(px(120.0)),
+                ColumnDef::new("Value"),
+            ],
+            vec![
+                vec!["Sleep mode".into(), "Deep".into()],
+                // Short and long rows
+                vec!["Open link timeout".into()],
+                vec!["Ignition".into(), "DIN1".into(), "extra".into()],
+            ],
+        )
+    }
+
+    struct Parameters;
+
+    impl Render for Parameters {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            table()
+        }
+    }
+
+    #[test]
+    fn columns_and_rows() {
+        let table = table();
+        assert_eq!(
+            table.columns(),
+            [
+                ColumnDef {
+                    header: "Parameter".into(),
+                    width: Some(px(120.0).into()),
+                },
+                ColumnDef::new("Value"),
+            ]
+        );
+        assert_eq!(table.rows().len(), 3);
+        assert_eq!(table.rows()[1], ["Open link timeout"]);
+    }
+
+    #[gpui::test]
+    fn renders_rows_of_any_length(cx: &mut TestAppContext) {
+        let (_view, cx) = cx.add_window_view(|_cx| Parameters);
+        cx.run_until_parked();
+    }
+}
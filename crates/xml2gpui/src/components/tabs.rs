@@ -0,0 +1,167 @@
+use gpui::*;
+
+use crate::bindings::BindingRegistry;
+use crate::context::RenderContext;
+use crate::handlers::HandlerRegistry;
+use crate::suggestions::SuggestionsRegistry;
+use crate::tree::{ChildRenderer, Component, ViewCache};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabDef {
+    pub label: String,
+    // Children of the <tab> element
+    pub body: Vec<Component>,
+}
+
+// Tab bar with the panel of the active tab below it
+pub struct Tabs {
+    tabs: Vec<TabDef>,
+    active_tab: usize,
+    // Panels are rendered by the view itself
+    renderer: ChildRenderer,
+    style: StyleRefinement,
+}
+
+impl Tabs {
+    pub fn new(tabs: Vec<TabDef>) -> Self {
+        Self {
+            tabs,
+            active_tab: 0,
+            renderer: ChildRenderer::default(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    pub fn active_tab(&self) -> usize {
+        self.active_tab
+    }
+
+    pub fn select(&mut self, index: usize, cx: &mut ViewContext<Self>) {
+        if index < self.tabs.len() && index != self.active_tab {
+            self.active_tab = index;
+            cx.notify();
+        }
+    }
+
+    // Labels can come from the context, and a reload can add or remove tabs
+    pub fn set_tabs(&mut self, tabs: Vec<TabDef>, cx: &mut ViewContext<Self>) {
+        if tabs == self.tabs {
+            return;
+        }
+        self.tabs = tabs;
+        self.active_tab = self.active_tab.min(self.tabs.len().saturating_sub(1));
+        cx.notify();
+    }
+
+    pub(crate) fn set_registries(
+        &mut self,
+        views: &ViewCache,
+        handlers: &HandlerRegistry,
+        bindings: &BindingRegistry,
+        suggestions: &SuggestionsRegistry,
+        context: &RenderContext,
+    ) {
+        self.renderer
+            .update(views, handlers, bindings, suggestions, context);
+    }
+}
+
+impl Render for Tabs {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let mut bar = div().flex().border_b_1().border_color(rgb(0xcccccc));
+        for (index, tab) in self.tabs.iter().enumerate() {
+            let mut button = div()
+                .id(("tab", index))
+                .px_3()
+                .py_1()
+                .cursor_pointer()
+                .child(tab.label.clone())
+                .on_click(cx.listener(move |this, _event, cx| this.select(index, cx)));
+            if index == self.active_tab {
+                button = button
+                    .border_b_2()
+                    .border_color(rgb(0x0055ff))
+                    .text_color(rgb(0x0055ff));
+            }
+            bar = bar.child(button);
+        }
+
+        let mut panel = div().flex().flex_col();
+        if let Some(tab) = self.tabs.get(self.active_tab) {
+            for child in &tab.body {
+                panel = panel.child(self.renderer.render(child, cx));
+            }
+        }
+
+        let mut element = div().flex().flex_col().child(bar).child(panel);
+        // Classes from the XML are applied on top of the default look
+        element.style().refine(&self.style);
+        element
+    }
+}
+
+impl Styled for Tabs {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tab(label: &str) -> TabDef {
+        TabDef {
+            label: label.into(),
+            body: Vec::new(),
+        }
+    }
+
+    fn active_tab(tabs: &View<Tabs>, cx: &mut VisualTestContext) -> usize {
+        tabs.update(cx, |tabs, _| tabs.active_tab())
+    }
+
+    #[gpui::test]
+    fn select_changes_the_active_tab(cx: &mut TestAppContext) {
+        let (tabs, cx) =
+            cx.add_window_view(|_cx| Tabs::new(vec![tab("GPS"), tab("I/O"), tab("GSM")]));
+        cx.run_until_parked();
+        assert_eq!(active_tab(&tabs, cx), 0);
+
+        // What clicking a tab does
+        tabs.update(cx, |tabs, cx| tabs.select(2, cx));
+        assert_eq!(active_tab(&tabs, cx), 2);
+        // Out of range is ignored
+        tabs.update(cx, |tabs, cx| tabs.select(3, cx));
+        assert_eq!(active_tab(&tabs, cx), 2);
+        cx.run_until_parked();
+    }
+
+    #[gpui::test]
+    fn set_tabs_keeps_the_active_tab_in_range(cx: &mut TestAppContext) {
+        let (tabs, cx) =
+            cx.add_window_view(|_cx| Tabs::new(vec![tab("GPS"), tab("I/O"), tab("GSM")]));
+        tabs.update(cx, |tabs, cx| tabs.select(2, cx));
+
+        tabs.update(cx, |tabs, cx| {
+            tabs.set_tabs(vec![tab("GPS"), tab("I/O")], cx)
+        });
+        assert_eq!(active_tab(&tabs, cx), 1);
+
+        tabs.update(cx, |tabs, cx| {
+            tabs.set_tabs(vec![tab("GPS (2)"), tab("I/O")], cx)
+        });
+        let labels = tabs.update(cx, |tabs, _| {
+            tabs.tabs
+                .iter()
+                .map(|tab| tab.label.clone())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(labels, ["GPS (2)", "I/O"]);
+        assert_eq!(active_tab(&tabs, cx), 1);
+
+        tabs.update(cx, |tabs, cx| tabs.set_tabs(Vec::new(), cx));
+        assert_eq!(active_tab(&tabs, cx), 0);
+        cx.run_until_parked();
+    }
+}
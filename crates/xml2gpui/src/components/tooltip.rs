@@ -0,0 +1,89 @@
+use gpui::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TooltipPosition {
+    Top,
+    Bottom,
+    // Above, or below when there is no room above
+    #[default]
+    Auto,
+}
+
+impl TooltipPosition {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "top" => Some(TooltipPosition::Top),
+            "bottom" => Some(TooltipPosition::Bottom),
+            "auto" => Some(TooltipPosition::Auto),
+            _ => None,
+        }
+    }
+}
+
+// Shows a text next to the target element while the mouse is over it
+#[derive(IntoElement)]
+pub struct Tooltip {
+    // Component number of the target, keeps the hover groups of the tooltips apart
+    number: i32,
+    target: AnyElement,
+    text: SharedString,
+    position: TooltipPosition,
+}
+
+impl Tooltip {
+    pub fn new(number: i32, target: impl IntoElement, text: impl Into<SharedString>) -> Self {
+        Self {
+            number,
+            target: target.into_any_element(),
+            text: text.into(),
+            position: TooltipPosition::default(),
+        }
+    }
+
+    pub fn position(mut self, position: TooltipPosition) -> Self {
+        self.position = position;
+        self
+    }
+}
+
+impl RenderOnce for Tooltip {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let group = SharedString::from(format!("tooltip-{}", self.number));
+
+        let bubble = div()
+            .invisible()
+            .group_hover(group.clone(), |style| style.visible())
+            .my_1()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(rgb(0x1f2937))
+            .text_color(rgb(0xffffff))
+            .text_sm()
+            .whitespace_nowrap()
+            .child(self.text);
+
+        // Overlays are drawn over everything else, so scrolling parents don't clip the tooltip.
+        // The overlay is laid out at the top or bottom edge of the target and grows away from it
+        let edge = div().absolute().left_0();
+        let (edge, overlay) = match self.position {
+            TooltipPosition::Top => (
+                edge.top_0(),
+                overlay().anchor(AnchorCorner::BottomLeft).snap_to_window(),
+            ),
+            TooltipPosition::Bottom => (
+                edge.top(relative(1.0)),
+                overlay().anchor(AnchorCorner::TopLeft).snap_to_window(),
+            ),
+            // Overlay switches the anchor when the tooltip doesn't fit in the window
+            TooltipPosition::Auto => (edge.top_0(), overlay().anchor(AnchorCorner::BottomLeft)),
+        };
+
+        div()
+            .id(("tooltip", self.number as usize))
+            .group(group)
+            .relative()
+            .child(self.target)
+            .child(edge.child(overlay.child(bubble)))
+    }
+}
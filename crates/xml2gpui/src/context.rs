@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+// Values for {key} placeholders in the text of .gpuiml elements, e.g. "Device: {device_model}"
+#[derive(Default, Clone, Debug)]
+pub struct RenderContext {
+    values: HashMap<String, String>,
+    // Keys already warned about. Text is interpolated on every render, so each missing key is
+    // only logged once, also by the clones the child renderers get
+    reported: Rc<RefCell<HashSet<String>>>,
+}
+
+impl RenderContext {
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    // Replaces every {key} with its value. Unknown keys are left as they are, so the missing
+    // value is visible in the UI
+    pub fn interpolate(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+
+            // Unclosed brace is just text
+            let Some(end) = after.find('}') else {
+                result.push_str(&rest[start..]);
+                return result;
+            };
+
+            let key = &after[..end];
+            match self.get(key.trim()) {
+                Some(value) => result.push_str(value),
+                None => {
+                    if self.reported.borrow_mut().insert(key.to_string()) {
+                        tracing::warn!("No value for {{{}}} in the render context", key);
+                    }
+                    result.push('{');
+                    result.push_str(key);
+                    result.push('}');
+                }
+            }
+            rest = &after[end + 1..];
+        }
+
+        result.push_str(rest);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> RenderContext {
+        let mut context = RenderContext::default();
+        context.set("device_model", "FMB920");
+        context.set("imei", "352094080000000");
+        context
+    }
+
+    #[test]
+    fn known_keys() {
+        let context = context();
+        assert_eq!(
+            context.interpolate("Device: {device_model}"),
+            "Device: FMB920"
+        );
+        assert_eq!(
+            context.interpolate("{device_model} ({imei})"),
+            "FMB920 (352094080000000)"
+        );
+        assert_eq!(context.interpolate("No placeholders"), "No placeholders");
+    }
+
+    #[test]
+    fn unknown_keys_are_kept() {
+        let context = context();
+        assert_eq!(
+            context.interpolate("Firmware: {firmware}"),
+            "Firmware: {firmware}"
+        );
+        assert_eq!(context.interpolate("{}"), "{}");
+        // Reported once, however many times it's rendered
+        assert_eq!(
+            context.interpolate("{firmware} {firmware}"),
+            "{firmware} {firmware}"
+        );
+        assert_eq!(context.clone().interpolate("{firmware}"), "{firmware}");
+        assert_eq!(context.reported.borrow().len(), 2);
+    }
+
+    #[test]
+    fn unclosed_brace() {
+        let context = context();
+        assert_eq!(
+            context.interpolate("Device: {device_model"),
+            "Device: {device_model"
+        );
+        assert_eq!(
+            context.interpolate("{imei} {device_model"),
+            "352094080000000 {device_model"
+        );
+    }
+
+    #[test]
+    fn keys_are_trimmed() {
+        let context = context();
+        assert_eq!(
+            context.interpolate("Device: { device_model }"),
+            "Device: FMB920"
+        );
+        // Unknown keys are kept as written
+        assert_eq!(context.interpolate("{ firmware }"), "{ firmware }");
+    }
+}
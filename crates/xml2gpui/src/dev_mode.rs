@@ -0,0 +1,195 @@
+use gpui::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::tree::Component;
+
+// Outlines every rendered component and shows what it is on hover, for finding out where
+// components start and end while working on a layout
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DevMode(pub bool);
+
+impl Global for DevMode {}
+
+impl DevMode {
+    // DEV_MODE=1 turns it on
+    pub fn from_env() -> Self {
+        Self(std::env::var("DEV_MODE").is_ok_and(|value| value == "1"))
+    }
+
+    pub fn enabled(cx: &AppContext) -> bool {
+        cx.has_global::<DevMode>() && cx.global::<DevMode>().0
+    }
+}
+
+// Time spent rendering each component of the frame, children included. Keys are child indexes
+// like in ComponentDiff, starting with the number of the component the render started from, as
+// views like tabs render their children separately from the rest of the tree
+#[derive(Default)]
+pub struct RenderProfile {
+    times: HashMap<Vec<usize>, Duration>,
+    path: Vec<usize>,
+    // Children rendered so far on each level of the path
+    rendered_children: Vec<usize>,
+}
+
+impl Global for RenderProfile {}
+
+// Heat is only shown once some component is this slow, otherwise the UI would be all green
+const SLOW_RENDER: Duration = Duration::from_millis(5);
+
+impl RenderProfile {
+    // Forgets the previous frame, called before rendering the root
+    pub fn reset(cx: &mut AppContext) {
+        cx.set_global(RenderProfile::default());
+    }
+
+    pub fn time(&self, path: &[usize]) -> Option<Duration> {
+        self.times.get(path).copied()
+    }
+
+    pub fn slowest(&self) -> Option<Duration> {
+        self.times.values().max().copied()
+    }
+
+    // Path of the component about to be rendered
+    pub(crate) fn enter(&mut self, component: &Component) -> Vec<usize> {
+        match self.rendered_children.last_mut() {
+            Some(index) => {
+                self.path.push(*index);
+                *index += 1;
+            }
+            None => self.path.push(component.number as usize),
+        }
+        self.rendered_children.push(0);
+        self.path.clone()
+    }
+
+    pub(crate) fn exit(&mut self, elapsed: Duration) {
+        self.times.insert(self.path.clone(), elapsed);
+        self.path.pop();
+        self.rendered_children.pop();
+    }
+}
+
+fn heat_color(elapsed: Duration) -> Rgba {
+    if elapsed < Duration::from_millis(1) {
+        rgba(0x22c55e40)
+    } else if elapsed <= SLOW_RENDER {
+        rgba(0xeab30840)
+    } else {
+        rgba(0xef444440)
+    }
+}
+
+const DASH: f32 = 4.0;
+const OUTLINE_COLOR: u32 = 0xff00ff99;
+
+// Wraps the rendered component in a div that outlines it and has the hover label. The wrapper is
+// a flex item of its own, so classes like flex-1 on the component may lay out differently
+pub(crate) fn inspect(
+    component: &Component,
+    path: Vec<usize>,
+    element: AnyElement,
+) -> Stateful<Div> {
+    let classes = component
+        .attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .map(|(_, classes)| classes.clone())
+        .unwrap_or_default();
+    let label = SharedString::from(format!(
+        "<{}> class=\"{}\" children: {}",
+        component.elem,
+        classes,
+        component.children.len()
+    ));
+
+    div()
+        .id(("dev-mode", component.number as usize))
+        .relative()
+        .child(element)
+        .child(outline(path.clone()))
+        // Tooltips only show for the innermost hovered element, unlike hover styles
+        .tooltip(move |cx| {
+            let mut label = label.to_string();
+            if let Some(elapsed) = profiled_time(&path, cx) {
+                label.push_str(&format!(" render: {} µs", elapsed.as_micros()));
+            }
+            cx.new_view(|_cx| DevModeLabel {
+                label: label.into(),
+            })
+            .into()
+        })
+}
+
+fn profiled_time(path: &[usize], cx: &AppContext) -> Option<Duration> {
+    if !cx.has_global::<RenderProfile>() {
+        return None;
+    }
+    cx.global::<RenderProfile>().time(path)
+}
+
+// GPUI borders are always solid, so the dashes are painted one by one. Painting happens after the
+// whole frame is rendered, so the heat can be compared to the slowest component
+fn outline(path: Vec<usize>) -> impl IntoElement {
+    canvas(move |bounds, cx| {
+        let slow_frame = cx.has_global::<RenderProfile>()
+            && cx
+                .global::<RenderProfile>()
+                .slowest()
+                .is_some_and(|slowest| slowest > SLOW_RENDER);
+        if slow_frame {
+            if let Some(elapsed) = profiled_time(&path, cx) {
+                cx.paint_quad(fill(bounds, heat_color(elapsed)));
+            }
+        }
+
+        let color = rgba(OUTLINE_COLOR);
+        let width = bounds.size.width / px(1.0);
+        let height = bounds.size.height / px(1.0);
+        let right = bounds.origin.x + bounds.size.width - px(1.0);
+        let bottom = bounds.origin.y + bounds.size.height - px(1.0);
+
+        let mut x = 0.0;
+        while x < width {
+            let dash = size(px(DASH.min(width - x)), px(1.0));
+            for y in [bounds.origin.y, bottom] {
+                let origin = point(bounds.origin.x + px(x), y);
+                cx.paint_quad(fill(Bounds::new(origin, dash), color));
+            }
+            x += DASH * 2.0;
+        }
+        let mut y = 0.0;
+        while y < height {
+            let dash = size(px(1.0), px(DASH.min(height - y)));
+            for x in [bounds.origin.x, right] {
+                let origin = point(x, bounds.origin.y + px(y));
+                cx.paint_quad(fill(Bounds::new(origin, dash), color));
+            }
+            y += DASH * 2.0;
+        }
+    })
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
+
+struct DevModeLabel {
+    label: SharedString,
+}
+
+impl Render for DevModeLabel {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(rgb(0x1f2937))
+            .text_color(rgb(0xffffff))
+            .text_xs()
+            .whitespace_nowrap()
+            .child(self.label.clone())
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+
+use crate::tree::{renumber, Component};
+
+// Paths are child indexes from the root, so [] is the root and [0, 2] is the third child of
+// the first child
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentChange {
+    // An empty path replaces the root
+    Inserted {
+        path: Vec<usize>,
+        component: Component,
+    },
+    Removed {
+        path: Vec<usize>,
+    },
+    // Added attributes have an empty old_val and removed ones an empty new_val.
+    // Events are compared as their on:* attributes
+    AttributeChanged {
+        path: Vec<usize>,
+        key: String,
+        old_val: String,
+        new_val: String,
+    },
+    // Empty text means no text
+    TextChanged {
+        path: Vec<usize>,
+        new_text: String,
+    },
+}
+
+pub struct ComponentDiff;
+
+impl ComponentDiff {
+    // Children are matched by index, which is cheap and good enough for edits to a hand written
+    // file. Component numbers are not compared, apply() renumbers the tree like the parser does
+    pub fn compute(old: &Component, new: &Component) -> Vec<ComponentChange> {
+        let mut changes = Vec::new();
+        diff_component(old, new, &mut Vec::new(), &mut changes);
+        changes
+    }
+
+    // Changes are applied in order, so they must come from compute() for this same tree
+    pub fn apply(diff: &[ComponentChange], root: &mut Component) {
+        for change in diff {
+            match change {
+                ComponentChange::Inserted { path, component } => match path.split_last() {
+                    Some((index, parent)) => {
+                        if let Some(parent) = component_at(root, parent) {
+                            let index = (*index).min(parent.children.len());
+                            parent.children.insert(index, component.clone());
+                        }
+                    }
+                    None => *root = component.clone(),
+                },
+                ComponentChange::Removed { path } => {
+                    if let Some((index, parent)) = path.split_last() {
+                        if let Some(parent) = component_at(root, parent) {
+                            if *index < parent.children.len() {
+                                parent.children.remove(*index);
+                            }
+                        }
+                    }
+                }
+                ComponentChange::AttributeChanged {
+                    path, key, new_val, ..
+                } => {
+                    if let Some(component) = component_at(root, path) {
+                        set_attribute(component, key, new_val);
+                    }
+                }
+                ComponentChange::TextChanged { path, new_text } => {
+                    if let Some(component) = component_at(root, path) {
+                        component.text = (!new_text.is_empty()).then(|| new_text.clone());
+                    }
+                }
+            }
+        }
+
+        renumber(root, &mut 1);
+    }
+}
+
+fn diff_component(
+    old: &Component,
+    new: &Component,
+    path: &mut Vec<usize>,
+    changes: &mut Vec<ComponentChange>,
+) {
+    // A different element is a different component, attributes can't be patched over
+    if old.elem != new.elem {
+        if !path.is_empty() {
+            changes.push(ComponentChange::Removed { path: path.clone() });
+        }
+        changes.push(ComponentChange::Inserted {
+            path: path.clone(),
+            component: new.clone(),
+        });
+        return;
+    }
+
+    let old_attributes = attribute_map(old);
+    let new_attributes = attribute_map(new);
+    let mut keys: Vec<&String> = old_attributes.keys().chain(new_attributes.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        let old_val = old_attributes.get(key).cloned().unwrap_or_default();
+        let new_val = new_attributes.get(key).cloned().unwrap_or_default();
+        if old_val != new_val {
+            changes.push(ComponentChange::AttributeChanged {
+                path: path.clone(),
+                key: key.clone(),
+                old_val,
+                new_val,
+            });
+        }
+    }
+
+    if old.text != new.text {
+        changes.push(ComponentChange::TextChanged {
+            path: path.clone(),
+            new_text: new.text.clone().unwrap_or_default(),
+        });
+    }
+
+    let common = old.children.len().min(new.children.len());
+    for index in 0..common {
+        path.push(index);
+        diff_component(&old.children[index], &new.children[index], path, changes);
+        path.pop();
+    }
+    // Extra children are removed from the end, so the indexes of the earlier ones stay valid
+    for index in (common..old.children.len()).rev() {
+        path.push(index);
+        changes.push(ComponentChange::Removed { path: path.clone() });
+        path.pop();
+    }
+    for (index, child) in new.children.iter().enumerate().skip(common) {
+        path.push(index);
+        changes.push(ComponentChange::Inserted {
+            path: path.clone(),
+            component: child.clone(),
+        });
+        path.pop();
+    }
+}
+
+fn attribute_map(component: &Component) -> HashMap<String, String> {
+    component
+        .attributes
+        .iter()
+        .cloned()
+        .chain(
+            component
+                .events
+                .iter()
+                .map(|(event, handler)| (format!("on:{}", event), handler.clone())),
+        )
+        .collect()
+}
+
+fn set_attribute(component: &mut Component, key: &str, value: &str) {
+    let (list, key) = match key.strip_prefix("on:") {
+        Some(event) => (&mut component.events, event),
+        None => (&mut component.attributes, key),
+    };
+
+    let existing = list.iter().position(|(k, _)| k == key);
+    match (existing, value.is_empty()) {
+        (Some(index), true) => {
+            list.remove(index);
+        }
+        (Some(index), false) => list[index].1 = value.to_string(),
+        (None, false) => list.push((key.to_string(), value.to_string())),
+        (None, true) => (),
+    }
+}
+
+fn component_at<'a>(root: &'a mut Component, path: &[usize]) -> Option<&'a mut Component> {
+    path.iter()
+        .try_fold(root, |component, index| component.children.get_mut(*index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::ComponentBuilder;
+
+    // Applying the diff of two trees to the first one gives the second one
+    fn assert_round_trip(old: Component, new: Component) -> Vec<ComponentChange> {
+        let diff = ComponentDiff::compute(&old, &new);
+        let mut patched = old;
+        ComponentDiff::apply(&diff, &mut patched);
+        assert_eq!(patched, new, "diff: {:?}", diff);
+        diff
+    }
+
+    fn text(text: &str) -> Component {
+        ComponentBuilder::elem("text").text(text).build()
+    }
+
+    fn list(children: Vec<Component>) -> Component {
+        children
+            .into_iter()
+            .fold(ComponentBuilder::elem("div"), |list, child| {
+                list.child(child)
+            })
+            .build()
+    }
+
+    #[test]
+    fn equal_trees_have_no_changes() {
+        let tree = list(vec![text("One"), text("Two")]);
+        assert!(assert_round_trip(tree.clone(), tree).is_empty());
+    }
+
+    #[test]
+    fn attribute_changes() {
+        let old = ComponentBuilder::elem("div")
+            .attr("class", "flex")
+            .attr("id", "root")
+            .build();
+        let new = ComponentBuilder::elem("div")
+            .attr("class", "flex flex-col")
+            .attr("title", "Settings")
+            .build();
+        let diff = assert_round_trip(old, new);
+        assert_eq!(diff.len(), 3);
+        assert!(diff.contains(&ComponentChange::AttributeChanged {
+            path: vec![],
+            key: "id".to_string(),
+            old_val: "root".to_string(),
+            new_val: String::new(),
+        }));
+    }
+
+    #[test]
+    fn text_changes() {
+        let diff = assert_round_trip(text("Hello"), text("Hello, world"));
+        assert_eq!(
+            diff,
+            [ComponentChange::TextChanged {
+                path: vec![],
+                new_text: "Hello, world".to_string(),
+            }]
+        );
+        assert_round_trip(text("Hello"), ComponentBuilder::elem("text").build());
+        assert_round_trip(ComponentBuilder::elem("text").build(), text("Hello"));
+    }
+
+    #[test]
+    fn event_changes() {
+        let button = |handler: &str| {
+            ComponentBuilder::elem("button")
+                .attr("on:click", handler)
+                .build()
+        };
+        let diff = assert_round_trip(button("save_config"), button("reload"));
+        assert_eq!(
+            diff,
+            [ComponentChange::AttributeChanged {
+                path: vec![],
+                key: "on:click".to_string(),
+                old_val: "save_config".to_string(),
+                new_val: "reload".to_string(),
+            }]
+        );
+        assert_round_trip(button("reload"), ComponentBuilder::elem("button").build());
+    }
+
+    #[test]
+    fn inserted_children() {
+        let diff = assert_round_trip(
+            list(vec![text("One")]),
+            list(vec![text("One"), text("Two"), text("Three")]),
+        );
+        assert_eq!(diff.len(), 2);
+        assert!(matches!(&diff[0], ComponentChange::Inserted { path, .. } if path == &[1]));
+    }
+
+    #[test]
+    fn removed_children() {
+        let diff = assert_round_trip(
+            list(vec![text("One"), text("Two"), text("Three")]),
+            list(vec![text("One")]),
+        );
+        assert_eq!(
+            diff,
+            [
+                ComponentChange::Removed { path: vec![2] },
+                ComponentChange::Removed { path: vec![1] },
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_changes() {
+        assert_round_trip(
+            list(vec![list(vec![text("One"), text("Two")]), text("Three")]),
+            list(vec![list(vec![text("Two")]), text("Three"), list(vec![])]),
+        );
+    }
+
+    #[test]
+    fn replaced_child() {
+        let diff = assert_round_trip(
+            list(vec![text("One")]),
+            list(vec![ComponentBuilder::elem("img")
+                .attr("src", "logo.svg")
+                .build()]),
+        );
+        assert!(matches!(&diff[0], ComponentChange::Removed { path } if path == &[0]));
+    }
+
+    #[test]
+    fn replaced_root() {
+        let diff = assert_round_trip(list(vec![text("One")]), text("One"));
+        assert!(matches!(&diff[..], [ComponentChange::Inserted { path, .. }] if path.is_empty()));
+    }
+}
@@ -0,0 +1,76 @@
+use gpui::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::bindings::BindingRegistry;
+use crate::context::RenderContext;
+use crate::handlers::HandlerRegistry;
+use crate::suggestions::SuggestionsRegistry;
+use crate::tree::{render_component, Component, ComponentType, PointerEvents, ViewCache};
+
+// Renders a component tree, but if rendering panics (bad color, missing attribute etc.)
+// shows a red fallback box instead of taking the whole window down
+pub struct ErrorBoundary<'a> {
+    component: &'a Component,
+    fallback: String,
+}
+
+impl<'a> ErrorBoundary<'a> {
+    pub fn new(component: &'a Component, fallback: impl Into<String>) -> Self {
+        Self {
+            component,
+            fallback: fallback.into(),
+        }
+    }
+
+    pub fn render(
+        self,
+        views: &mut ViewCache,
+        handlers: &HandlerRegistry,
+        bindings: &BindingRegistry,
+        suggestions: &SuggestionsRegistry,
+        context: &RenderContext,
+        cx: &mut WindowContext,
+    ) -> ComponentType {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            render_component(
+                self.component,
+                views,
+                handlers,
+                bindings,
+                suggestions,
+                context,
+                cx,
+            )
+        }));
+
+        match result {
+            Ok(element) => element,
+            Err(payload) => {
+                // Panic payload is a &str or String when panic!() was given a message
+                let reason = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown error".to_string());
+                tracing::error!("Rendering <{}> failed: {}", self.component.elem, reason);
+                PointerEvents::reset(cx);
+
+                ComponentType::Div(
+                    div()
+                        .id("error-boundary")
+                        .m_2()
+                        .p_2()
+                        .border_1()
+                        .rounded_md()
+                        .border_color(rgb(0xdc2626))
+                        .bg(rgb(0xfee2e2))
+                        .text_color(rgb(0xb91c1c))
+                        .child(format!(
+                            "{} (<{}>: {})",
+                            self.fallback, self.component.elem, reason
+                        )),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use gpui::WindowContext;
+use std::{collections::HashMap, sync::Arc};
+
+pub type Handler = Arc<dyn Fn(&mut WindowContext) + Send + Sync>;
+
+// Named Rust callbacks that XML event attributes (on:click="save_config") refer to
+#[derive(Default, Clone)]
+pub struct HandlerRegistry {
+    handlers: HashMap<String, Handler>,
+}
+
+impl HandlerRegistry {
+    pub fn register_handler(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&mut WindowContext) + Send + Sync + 'static,
+    ) {
+        self.handlers.insert(name.into(), Arc::new(handler));
+    }
+
+    pub fn get(&self, name: &str) -> Option<Handler> {
+        self.handlers.get(name).cloned()
+    }
+}
@@ -1,2 +1,14 @@
+pub mod bindings;
+pub mod colors;
 pub mod components;
+pub mod context;
+pub mod dev_mode;
+pub mod diff;
+pub mod error_boundary;
+pub mod handlers;
+pub mod suggestions;
+pub mod theme;
 pub mod tree;
+pub mod validator;
+
+pub use xml2gpui_macros::gpuiml;
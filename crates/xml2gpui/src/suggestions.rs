@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Returns the suggestions for the typed text
+pub type SuggestionsProvider = Arc<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+// Suggestion lists that autocomplete inputs can use with suggestions="name" in the XML
+#[derive(Default, Clone)]
+pub struct SuggestionsRegistry {
+    providers: HashMap<String, SuggestionsProvider>,
+}
+
+impl SuggestionsRegistry {
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        provider: impl Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    ) {
+        self.providers.insert(name.into(), Arc::new(provider));
+    }
+
+    // Suggests the values that contain the typed text, ignoring case
+    pub fn register_list(&mut self, name: impl Into<String>, values: Vec<String>) {
+        self.register(name, move |query| {
+            let query = query.to_lowercase();
+            values
+                .iter()
+                .filter(|value| value.to_lowercase().contains(&query))
+                .cloned()
+                .collect()
+        });
+    }
+
+    pub fn get(&self, name: &str) -> Option<SuggestionsProvider> {
+        self.providers.get(name).cloned()
+    }
+}
@@ -0,0 +1,282 @@
+use gpui::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::sync::Once;
+
+// Names of the color tokens, usable in classes like bg-primary, text-error and border-surface
+pub const TOKENS: [&str; 7] = [
+    "primary",
+    "secondary",
+    "background",
+    "surface",
+    "error",
+    "warning",
+    "success",
+];
+
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "IO error: {}", e),
+            ThemeError::Toml(e) => write!(f, "invalid theme: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ThemeMode {
+    #[default]
+    Light,
+    Dark,
+}
+
+// Colors and type scale of the UI files, read from a theme.toml. Tokens missing from the file
+// keep their default value
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub colors: ThemeColors,
+    pub typography: Typography,
+    // Which palette the tokens come from. Not in the file, it follows the OS or the user
+    #[serde(skip)]
+    pub mode: ThemeMode,
+}
+
+// [colors.light] and [colors.dark] sections
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeColors {
+    pub light: Palette,
+    pub dark: Palette,
+}
+
+// Hex colors like "#0055ff", with an optional alpha. Colors missing from the file come from the
+// default palette of the mode
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Palette {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surface: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+}
+
+impl Palette {
+    fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "primary" => self.primary.as_deref(),
+            "secondary" => self.secondary.as_deref(),
+            "background" => self.background.as_deref(),
+            "surface" => self.surface.as_deref(),
+            "error" => self.error.as_deref(),
+            "warning" => self.warning.as_deref(),
+            "success" => self.success.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+// Default palettes, in the order of TOKENS
+const LIGHT_DEFAULTS: [u32; 7] = [
+    0x0055ff, 0x6b7280, 0xffffff, 0xf3f4f6, 0xef4444, 0xf59e0b, 0x22c55e,
+];
+const DARK_DEFAULTS: [u32; 7] = [
+    0x3b82f6, 0x9ca3af, 0x111827, 0x1f2937, 0xf87171, 0xfbbf24, 0x4ade80,
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Typography {
+    pub base_size_px: f32,
+    // Each step of the type scale is this much larger than the previous one
+    pub scale_ratio: f32,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            base_size_px: 14.0,
+            scale_ratio: 1.25,
+        }
+    }
+}
+
+impl Global for Theme {}
+
+impl Theme {
+    pub fn load(path: &Path) -> Result<Theme, ThemeError> {
+        let toml = std::fs::read_to_string(path).map_err(ThemeError::Io)?;
+        toml::from_str(&toml).map_err(ThemeError::Toml)
+    }
+
+    // Registered theme, or the default one when the app hasn't registered any
+    pub fn current(cx: &AppContext) -> Theme {
+        if cx.has_global::<Theme>() {
+            return cx.global::<Theme>().clone();
+        }
+        // Every render asks for the theme, once is enough to tell
+        static WARN_DEFAULT: Once = Once::new();
+        WARN_DEFAULT.call_once(|| {
+            tracing::warn!("No theme registered, theme colors like bg-primary use the defaults");
+        });
+        Theme::default()
+    }
+
+    // Switches between the light and dark palette and re-renders all windows
+    pub fn toggle_mode(cx: &mut AppContext) {
+        let mode = match Theme::current(cx).mode {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::Light,
+        };
+        Theme::set_mode(mode, cx);
+    }
+
+    pub fn set_mode(mode: ThemeMode, cx: &mut AppContext) {
+        let mut theme = Theme::current(cx);
+        if theme.mode == mode {
+            return;
+        }
+        theme.mode = mode;
+        cx.set_global(theme);
+        cx.refresh();
+    }
+
+    pub fn is_dark(&self) -> bool {
+        self.mode == ThemeMode::Dark
+    }
+
+    pub fn token(&self, name: &str) -> Option<Rgba> {
+        let index = TOKENS.iter().position(|token| *token == name)?;
+        let (palette, defaults) = match self.mode {
+            ThemeMode::Light => (&self.colors.light, LIGHT_DEFAULTS),
+            ThemeMode::Dark => (&self.colors.dark, DARK_DEFAULTS),
+        };
+        let Some(hex) = palette.get(name) else {
+            return Some(rgb(defaults[index]));
+        };
+        let color = parse_hex(hex);
+        if color.is_none() {
+            tracing::warn!("Invalid color \"{}\" for theme token {}", hex, name);
+        }
+        color
+    }
+
+    // Font size of a step on the type scale, 0 being the base size
+    pub fn font_size(&self, step: i32) -> Pixels {
+        let typography = &self.typography;
+        px(typography.base_size_px * typography.scale_ratio.powi(step))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Rgba> {
+    let hex = hex.strip_prefix('#')?;
+    // from_str_radix would also accept a leading +
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(rgb(value)),
+        8 => Some(rgba(value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn theme() -> Theme {
+        toml::from_str(
+            r##"
+            [colors.light]
+            primary = "#112233"
+            error = "#ff000080"
+
+            [colors.dark]
+            primary = "#445566"
+            "##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn tokens() {
+        let theme = theme();
+        assert_eq!(theme.token("primary"), Some(rgb(0x112233)));
+        assert_eq!(theme.token("error"), Some(rgba(0xff000080)));
+        assert_eq!(theme.token("accent"), None);
+    }
+
+    #[test]
+    fn missing_tokens_use_the_defaults() {
+        let theme = theme();
+        assert_eq!(theme.token("secondary"), Some(rgb(0x6b7280)));
+        assert_eq!(theme.typography, Typography::default());
+        for (token, default) in TOKENS.into_iter().zip(LIGHT_DEFAULTS) {
+            assert_eq!(Theme::default().token(token), Some(rgb(default)));
+        }
+    }
+
+    #[test]
+    fn invalid_colors() {
+        for hex in ["112233", "#12345", "#11223g", "#+12345", "#+1234567"] {
+            assert_eq!(parse_hex(hex), None, "{}", hex);
+        }
+        let mut theme = theme();
+        theme.colors.light.primary = Some("blue".to_string());
+        assert_eq!(theme.token("primary"), None);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut theme = theme();
+        theme.typography.base_size_px = 16.0;
+        let toml = toml::to_string(&theme).unwrap();
+        assert_eq!(toml::from_str::<Theme>(&toml).unwrap(), theme);
+        // Missing tokens aren't written as empty values
+        assert!(!toml.contains("secondary"));
+    }
+
+    #[test]
+    fn dark_mode_tokens() {
+        let mut theme = theme();
+        assert!(!theme.is_dark());
+        theme.mode = ThemeMode::Dark;
+        assert!(theme.is_dark());
+        assert_eq!(theme.token("primary"), Some(rgb(0x445566)));
+        // Tokens missing from the dark palette use the dark defaults, not the light colors
+        assert_eq!(theme.token("error"), Some(rgb(0xf87171)));
+        assert_eq!(theme.token("background"), Some(rgb(0x111827)));
+        // Back to light
+        theme.mode = ThemeMode::Light;
+        assert_eq!(theme.token("primary"), Some(rgb(0x112233)));
+    }
+
+    #[test]
+    fn mode_is_not_saved() {
+        let mut theme = theme();
+        theme.mode = ThemeMode::Dark;
+        let theme: Theme = toml::from_str(&toml::to_string(&theme).unwrap()).unwrap();
+        assert_eq!(theme.mode, ThemeMode::Light);
+    }
+}
@@ -1,102 +1,475 @@
 use gpui::*;
 use gpui::{div, prelude::*, px, Render, SharedString, Styled, View, WindowContext};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Instant;
 
-use quick_xml::events::Event;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::reader::Reader;
+use quick_xml::writer::Writer;
 
+use crate::bindings::BindingRegistry;
+use crate::colors::tailwind_color;
+use crate::components::accordion::AccordionSection;
+use crate::components::alert::{self, Alert};
+use crate::components::breadcrumb::{Breadcrumb, Crumb};
+use crate::components::drawer::{Drawer, DrawerSide};
+use crate::components::gradient::{Gradient, GradientDirection};
 use crate::components::input;
+use crate::components::modal::Modal;
+use crate::components::pagination::Pagination;
+use crate::components::progress::ProgressBar;
+use crate::components::spinner::{Spinner, SpinnerSize};
+use crate::components::table::{ColumnDef, Table};
+use crate::components::tabs::{TabDef, Tabs};
+use crate::components::tooltip::{Tooltip, TooltipPosition};
+use crate::context::RenderContext;
+use crate::dev_mode::{inspect, DevMode, RenderProfile};
+use crate::handlers::{Handler, HandlerRegistry};
+use crate::suggestions::SuggestionsRegistry;
+use crate::theme::{Theme, TOKENS as THEME_TOKENS};
 use xml2gpui_macros::tailwind_to_gpui;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Component {
     pub elem: String,
     pub text: Option<String>,
     pub attributes: Vec<(String, String)>,
+    // Event attributes like on:click="save_config" as ("click", "save_config")
+    pub events: Vec<(String, String)>,
     pub children: Vec<Component>,
     pub number: i32,
 }
 
-pub fn parse_xml(xml: String) -> Component {
-    let mut component_number = 1;
-    let mut reader = Reader::from_str(xml.as_str());
+#[derive(Debug)]
+pub enum ParseError {
+    Utf8Error(std::str::Utf8Error),
+    AttributeDecodeError(quick_xml::Error),
+    UnescapeError(quick_xml::escape::EscapeError),
+    // The document had no root element
+    EmptyStack,
+    XmlError(quick_xml::Error),
+    CircularInclude(PathBuf),
+    Io(PathBuf, std::io::Error),
+    // Where in the XML the error happened. Line and column start from 1, the snippet has the
+    // line and the two before it
+    Located {
+        path: Option<PathBuf>,
+        line: usize,
+        column: usize,
+        snippet: String,
+        error: Box<ParseError>,
+    },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Utf8Error(e) => write!(f, "invalid UTF-8 in element name: {}", e),
+            ParseError::AttributeDecodeError(e) => write!(f, "invalid attribute: {}", e),
+            ParseError::UnescapeError(e) => write!(f, "invalid escape in text: {}", e),
+            ParseError::EmptyStack => write!(f, "document has no root element"),
+            ParseError::XmlError(e) => write!(f, "{}", e),
+            ParseError::CircularInclude(path) => {
+                write!(f, "circular include of {}", path.display())
+            }
+            ParseError::Io(path, e) => write!(f, "could not read {}: {}", path.display(), e),
+            ParseError::Located {
+                path,
+                line,
+                column,
+                error,
+                ..
+            } => match path {
+                Some(path) => write!(f, "{}:{}:{}: {}", path.display(), line, column, error),
+                None => write!(f, "line {}, column {}: {}", line, column, error),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Component {
+    // Plain div showing a message, used to put parse errors on screen instead of crashing
+    pub fn error(message: impl Into<String>) -> Self {
+        Component {
+            elem: "div".to_string(),
+            text: Some(message.into()),
+            attributes: vec![],
+            events: vec![],
+            children: vec![],
+            number: 0,
+        }
+    }
+
+    // Depth-first search for the node whose id attribute matches, starting from self
+    pub fn find_by_id<'a>(&'a self, id: &str) -> Option<&'a Component> {
+        self.find(&|c: &Component| c.attributes.iter().any(|(k, v)| k == "id" && v == id))
+    }
+
+    pub fn find_by_id_mut<'a>(&'a mut self, id: &str) -> Option<&'a mut Component> {
+        self.find_mut(&|c: &Component| c.attributes.iter().any(|(k, v)| k == "id" && v == id))
+    }
+
+    // First node with the element name, in depth-first order
+    pub fn find_by_elem<'a>(&'a self, elem: &str) -> Option<&'a Component> {
+        self.find(&|c: &Component| c.elem == elem)
+    }
+
+    pub fn find_by_elem_mut<'a>(&'a mut self, elem: &str) -> Option<&'a mut Component> {
+        self.find_mut(&|c: &Component| c.elem == elem)
+    }
+
+    // All nodes with the element name in depth-first order, also the ones nested inside another
+    // match. find_all_mut leaves those out
+    pub fn find_all<'a>(&'a self, elem: &str) -> Vec<&'a Component> {
+        let mut found = Vec::new();
+        self.collect(elem, &mut found);
+        found
+    }
+
+    // Like find_all, but only the outermost matches: a node and its children can't both be
+    // borrowed mutably, so matches nested inside another match are not included
+    pub fn find_all_mut<'a>(&'a mut self, elem: &str) -> Vec<&'a mut Component> {
+        let mut found = Vec::new();
+        self.collect_mut(elem, &mut found);
+        found
+    }
+
+    fn find<'a>(&'a self, matches: &dyn Fn(&Component) -> bool) -> Option<&'a Component> {
+        if matches(self) {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(matches))
+    }
+
+    fn find_mut<'a>(
+        &'a mut self,
+        matches: &dyn Fn(&Component) -> bool,
+    ) -> Option<&'a mut Component> {
+        if matches(self) {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_mut(matches))
+    }
+
+    fn collect<'a>(&'a self, elem: &str, found: &mut Vec<&'a Component>) {
+        if self.elem == elem {
+            found.push(self);
+        }
+        for child in &self.children {
+            child.collect(elem, found);
+        }
+    }
+
+    fn collect_mut<'a>(&'a mut self, elem: &str, found: &mut Vec<&'a mut Component>) {
+        if self.elem == elem {
+            found.push(self);
+            return;
+        }
+        for child in &mut self.children {
+            child.collect_mut(elem, found);
+        }
+    }
+
+    // Serializes the tree back to .gpuiml. Parsing the result gives back an equal tree, except for
+    // attribute order and whitespace around text, which the parser trims
+    pub fn to_xml(&self) -> String {
+        let mut writer = Writer::new(Vec::new());
+        self.write_xml(&mut writer)
+            .expect("Writing XML to memory can't fail");
+        String::from_utf8(writer.into_inner()).expect("Component strings are valid UTF-8")
+    }
+
+    fn write_xml<W: std::io::Write>(&self, writer: &mut Writer<W>) -> quick_xml::Result<()> {
+        let mut start = BytesStart::new(self.elem.as_str());
+        for (key, value) in &self.attributes {
+            start.push_attribute((key.as_str(), value.as_str()));
+        }
+        for (event, handler) in &self.events {
+            start.push_attribute((format!("on:{}", event).as_str(), handler.as_str()));
+        }
+
+        if self.text.is_none() && self.children.is_empty() {
+            return writer.write_event(Event::Empty(start));
+        }
+
+        writer.write_event(Event::Start(start))?;
+        if let Some(text) = &self.text {
+            writer.write_event(Event::Text(BytesText::new(text)))?;
+        }
+        for child in &self.children {
+            child.write_xml(writer)?;
+        }
+        writer.write_event(Event::End(BytesEnd::new(self.elem.as_str())))
+    }
+}
+
+// Builds component trees in Rust without writing XML, e.g.
+// ComponentBuilder::elem("div").attr("class", "flex").text("Hello").build()
+pub struct ComponentBuilder {
+    component: Component,
+}
+
+impl ComponentBuilder {
+    pub fn elem(name: impl Into<String>) -> Self {
+        Self {
+            component: Component {
+                elem: name.into(),
+                text: None,
+                attributes: vec![],
+                events: vec![],
+                children: vec![],
+                number: 0,
+            },
+        }
+    }
+
+    // on:* attributes become events, like in the parser
+    pub fn attr(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key = key.into();
+        match key.strip_prefix("on:") {
+            Some(event) => self
+                .component
+                .events
+                .push((event.to_string(), value.into())),
+            None => self.component.attributes.push((key, value.into())),
+        }
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.component.text = Some(text.into());
+        self
+    }
+
+    pub fn child(mut self, child: Component) -> Self {
+        self.component.children.push(child);
+        self
+    }
+
+    // Numbers the tree like the parser does, so the result equals the parsed XML
+    pub fn build(mut self) -> Component {
+        renumber(&mut self.component, &mut 1);
+        self.component
+    }
+}
+
+// Depth-first numbering the parser uses
+pub(crate) fn renumber(component: &mut Component, number: &mut i32) {
+    component.number = *number;
+    *number += 1;
+    for child in &mut component.children {
+        renumber(child, number);
+    }
+}
+
+// Files opened while parsing, so the caller can watch them for changes
+#[derive(Debug, Default)]
+pub struct ParseContext {
+    // Files being parsed right now, i.e. the chain of includes down to the current file
+    open_files: HashSet<PathBuf>,
+    loaded_files: HashSet<PathBuf>,
+}
+
+impl ParseContext {
+    // Canonical paths of the root file and everything it includes, also the ones that failed
+    // to parse
+    pub fn loaded_files(&self) -> impl Iterator<Item = &Path> {
+        self.loaded_files.iter().map(PathBuf::as_path)
+    }
+}
+
+pub fn parse_xml(xml: String) -> Result<Component, ParseError> {
+    parse_xml_source(&xml, None, &mut ParseContext::default(), &mut 1)
+}
+
+// Parses a .gpuiml file. <include src="..."/> paths are resolved relative to the including file
+pub fn parse_file(path: &Path) -> Result<Component, ParseError> {
+    parse_file_with_context(path, &mut ParseContext::default())
+}
+
+pub fn parse_file_with_context(
+    path: &Path,
+    context: &mut ParseContext,
+) -> Result<Component, ParseError> {
+    parse_included_file(path, context, &mut 1)
+}
+
+fn parse_included_file(
+    path: &Path,
+    context: &mut ParseContext,
+    component_number: &mut i32,
+) -> Result<Component, ParseError> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+    // A file that is already being parsed further up would include itself forever
+    if !context.open_files.insert(path.clone()) {
+        return Err(ParseError::CircularInclude(path));
+    }
+    context.loaded_files.insert(path.clone());
+
+    let component = match std::fs::read_to_string(&path) {
+        Ok(xml) => parse_xml_source(&xml, Some(&path), context, component_number),
+        Err(e) => Err(ParseError::Io(path.clone(), e)),
+    };
+
+    context.open_files.remove(&path);
+    component
+}
+
+// Path is None for XML that didn't come from a file. Includes are then relative to the working
+// directory
+fn parse_xml_source(
+    xml: &str,
+    path: Option<&Path>,
+    context: &mut ParseContext,
+    component_number: &mut i32,
+) -> Result<Component, ParseError> {
+    let mut reader = Reader::from_str(xml);
     reader
         .expand_empty_elements(true)
         .check_end_names(true)
         .trim_text(true);
 
+    let base_dir = path.and_then(Path::parent);
+    read_components(&mut reader, base_dir, context, component_number).map_err(|error| match error {
+        // Errors from included files are already located in those files
+        ParseError::Located { .. } | ParseError::CircularInclude(_) | ParseError::Io(..) => error,
+        error => locate(error, xml, reader.buffer_position(), path),
+    })
+}
+
+fn locate(error: ParseError, xml: &str, position: usize, path: Option<&Path>) -> ParseError {
+    let mut position = position.min(xml.len());
+    while !xml.is_char_boundary(position) {
+        position -= 1;
+    }
+    let before = &xml[..position];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |index| index + 1) + 1;
+    let snippet = xml
+        .lines()
+        .enumerate()
+        .skip(line.saturating_sub(3))
+        .take(line.min(3))
+        .map(|(index, text)| format!("{:>4} | {}", index + 1, text))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    ParseError::Located {
+        path: path.map(Path::to_path_buf),
+        line,
+        column,
+        snippet,
+        error: Box::new(error),
+    }
+}
+
+fn read_components(
+    reader: &mut Reader<&[u8]>,
+    base_dir: Option<&Path>,
+    context: &mut ParseContext,
+    component_number: &mut i32,
+) -> Result<Component, ParseError> {
     let mut buf = Vec::new();
     let mut stack: Vec<Component> = Vec::new();
 
     loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Eof) => break,
-            Ok(event) => match event {
-                Event::Start(ref e) | Event::Empty(ref e) => {
-                    let elem_name = String::from_utf8(e.local_name().as_ref().to_vec()).unwrap();
-                    let attributes = e
-                        .html_attributes()
-                        .map(|a| {
-                            if let Ok(a) = a {
-                                (
-                                    String::from_utf8(a.key.local_name().as_ref().to_vec())
-                                        .unwrap(),
-                                    a.decode_and_unescape_value(&reader).unwrap().into_owned(),
-                                )
-                            } else {
-                                // println!("Attributes are: {:?}", e.attributes());
-                                // panic!("Error reading attribute");
-                                ("error".to_string(), "error".to_string())
-                            }
-                        })
-                        .collect::<Vec<(String, String)>>();
+        let event = reader
+            .read_event_into(&mut buf)
+            .map_err(ParseError::XmlError)?;
+        match event {
+            Event::Eof => break,
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let elem_name = std::str::from_utf8(e.local_name().as_ref())
+                    .map_err(ParseError::Utf8Error)?
+                    .to_string();
+                // Full attribute name is used, because local_name() would strip "on:" from "on:click"
+                let (events, attributes): (Vec<_>, Vec<_>) = e
+                    .html_attributes()
+                    .map(|a| {
+                        let a = a.map_err(|e| ParseError::AttributeDecodeError(e.into()))?;
+                        let key = std::str::from_utf8(a.key.as_ref())
+                            .map_err(ParseError::Utf8Error)?
+                            .to_string();
+                        let value = a
+                            .decode_and_unescape_value(reader)
+                            .map_err(ParseError::AttributeDecodeError)?
+                            .into_owned();
+                        Ok((key, value))
+                    })
+                    .collect::<Result<Vec<_>, ParseError>>()?
+                    .into_iter()
+                    .partition(|(k, _)| k.starts_with("on:"));
+                let events = events
+                    .into_iter()
+                    .map(|(k, v)| (k["on:".len()..].to_string(), v))
+                    .collect();
 
+                let component = if elem_name == "include" {
+                    // Included file's root takes the place of the <include> element
+                    let src = attributes
+                        .iter()
+                        .find(|(k, _)| k == "src")
+                        .map(|(_, v)| v.clone())
+                        .unwrap_or_default();
+                    let path = match base_dir {
+                        Some(dir) => dir.join(&src),
+                        None => PathBuf::from(&src),
+                    };
+                    parse_included_file(&path, context, component_number)?
+                } else {
                     let component = Component {
                         elem: elem_name,
                         text: None,
                         attributes,
+                        events,
                         children: Vec::new(),
-                        number: component_number,
+                        number: *component_number,
                     };
-                    component_number += 1;
+                    *component_number += 1;
+                    component
+                };
 
-                    if let Event::Empty(_) = event {
-                        // For Event::Empty, add directly to the parent if exists
-                        if let Some(parent) = stack.last_mut() {
-                            parent.children.push(component);
-                        }
-                    } else {
-                        // For Event::Start, push onto the stack for potential nesting
-                        stack.push(component);
-                    }
-                }
-                Event::End(_) => {
-                    if stack.len() > 1 {
-                        let finished_component = stack.pop().unwrap();
-                        if let Some(parent) = stack.last_mut() {
-                            parent.children.push(finished_component);
-                        }
+                if let Event::Empty(_) = event {
+                    // For Event::Empty, add directly to the parent if exists
+                    if let Some(parent) = stack.last_mut() {
+                        parent.children.push(component);
                     }
+                } else {
+                    // For Event::Start, push onto the stack for potential nesting
+                    stack.push(component);
                 }
-                Event::Text(e) => {
-                    let text = e.unescape().unwrap();
+            }
+            Event::End(_) => {
+                if stack.len() > 1 {
+                    let finished_component = stack.pop().ok_or(ParseError::EmptyStack)?;
                     if let Some(parent) = stack.last_mut() {
-                        parent.text = Some(text.into_owned());
+                        parent.children.push(finished_component);
                     }
                 }
-                _ => (),
-            },
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            }
+            Event::Text(e) => {
+                let text = e.unescape().map_err(|e| match e {
+                    quick_xml::Error::EscapeError(e) => ParseError::UnescapeError(e),
+                    e => ParseError::XmlError(e),
+                })?;
+                if let Some(parent) = stack.last_mut() {
+                    parent.text = Some(text.into_owned());
+                }
+            }
+            _ => (),
         }
         buf.clear();
     }
 
-    stack.pop().unwrap_or_else(|| Component {
-        elem: "error".to_string(),
-        text: Some("error".to_string()),
-        attributes: vec![],
-        children: vec![],
-        number: 0,
-    })
+    stack.pop().ok_or(ParseError::EmptyStack)
 }
 
 // I can't use dynamic trait objects, because Styled and IntoElement are not object-safe (have : Sized supertrait)
@@ -106,62 +479,300 @@ pub enum ComponentType {
     Div(Stateful<Div>),
     Img(Img),
     Svg(Svg),
-    Input(Input),
+    // Inputs are type-erased so that every new input type doesn't need its own variant
+    Input(AnyElement),
+    Table(Table),
+    Tabs(View<Tabs>),
+    Modal(View<Modal>),
+    Tooltip(Tooltip),
+    Accordion(Vec<View<AccordionSection>>),
+    ProgressBar(View<ProgressBar>),
+    Alert(View<Alert>),
+    Spinner(View<Spinner>),
+    Breadcrumb(Breadcrumb),
+    Pagination(View<Pagination>),
+    Drawer(View<Drawer>),
+}
+
+impl ComponentType {
+    pub fn into_any_element(self) -> AnyElement {
+        match self {
+            ComponentType::Div(div) => div.into_any_element(),
+            ComponentType::Img(img) => img.into_any_element(),
+            ComponentType::Svg(svg) => svg.into_any_element(),
+            ComponentType::Input(input) => input,
+            ComponentType::Table(table) => table.into_any_element(),
+            ComponentType::Tabs(tabs) => tabs.into_any_element(),
+            ComponentType::Modal(modal) => modal.into_any_element(),
+            ComponentType::Tooltip(tooltip) => tooltip.into_any_element(),
+            ComponentType::ProgressBar(progress) => progress.into_any_element(),
+            ComponentType::Alert(alert) => alert.into_any_element(),
+            ComponentType::Spinner(spinner) => spinner.into_any_element(),
+            ComponentType::Breadcrumb(breadcrumb) => breadcrumb.into_any_element(),
+            ComponentType::Pagination(pagination) => pagination.into_any_element(),
+            ComponentType::Drawer(drawer) => drawer.into_any_element(),
+            ComponentType::Accordion(sections) => div()
+                .flex()
+                .flex_col()
+                .children(sections)
+                .into_any_element(),
+        }
+    }
+}
+
+// Stateful components (inputs etc.) are gpui views that must survive between renders,
+// so they are cached by the component number
+#[derive(Default)]
+pub struct ViewCache {
+    views: HashMap<i32, AnyView>,
+    // <modal>s by id for on:click="modal:<id>". Shared with the click handlers, because the
+    // modal may come after the element that opens it
+    modals: Rc<RefCell<HashMap<String, View<Modal>>>>,
+    // <drawer>s by id for on:click="drawer:<id>", like the modals
+    drawers: Rc<RefCell<HashMap<String, View<Drawer>>>>,
+}
+
+impl ViewCache {
+    pub fn get_or_insert<V: Render>(
+        &mut self,
+        number: i32,
+        cx: &mut WindowContext,
+        build: impl FnOnce(&mut ViewContext<V>) -> V,
+    ) -> View<V> {
+        if let Some(view) = self
+            .views
+            .get(&number)
+            .and_then(|view| view.clone().downcast::<V>().ok())
+        {
+            return view;
+        }
+
+        let view = cx.new_view(build);
+        self.views.insert(number, view.clone().into());
+        view
+    }
+
+    // The ids a child renderer's elements can open are the parent's, so that a modal at the
+    // root opens from inside a tab and a modal inside a tab from the root. Same for drawers
+    fn share_ids(&mut self, parent: &ViewCache) {
+        self.modals = parent.modals.clone();
+        self.drawers = parent.drawers.clone();
+    }
+
+    // Drop all cached views, e.g. when the XML file is reloaded
+    pub fn clear(&mut self) {
+        self.views.clear();
+        self.modals.borrow_mut().clear();
+        self.drawers.borrow_mut().clear();
+    }
+}
+
+// What render_component needs, for views like Tabs that render components of their own.
+// The registries are copied from the parent on every render, because they can change after the
+// view is created. The cached views are the child's own, but the modal and drawer ids are shared
+#[derive(Default)]
+pub(crate) struct ChildRenderer {
+    views: ViewCache,
+    handlers: HandlerRegistry,
+    bindings: BindingRegistry,
+    suggestions: SuggestionsRegistry,
+    context: RenderContext,
 }
 
-pub enum Input {
-    InputNumber(input::number::InputNumber),
-    InputText(input::text::InputText),
-    InputCheckbox(input::checkbox::InputCheckbox),
-    InputSelect(input::select::InputSelect),
+impl ChildRenderer {
+    pub(crate) fn update(
+        &mut self,
+        views: &ViewCache,
+        handlers: &HandlerRegistry,
+        bindings: &BindingRegistry,
+        suggestions: &SuggestionsRegistry,
+        context: &RenderContext,
+    ) {
+        self.views.share_ids(views);
+        self.handlers = handlers.clone();
+        self.bindings = bindings.clone();
+        self.suggestions = suggestions.clone();
+        self.context = context.clone();
+    }
+
+    pub(crate) fn render(&mut self, component: &Component, cx: &mut WindowContext) -> AnyElement {
+        render_component(
+            component,
+            &mut self.views,
+            &self.handlers,
+            &self.bindings,
+            &self.suggestions,
+            &self.context,
+            cx,
+        )
+        .into_any_element()
+    }
+}
+
+pub fn render_component(
+    component: &Component,
+    views: &mut ViewCache,
+    handlers: &HandlerRegistry,
+    bindings: &BindingRegistry,
+    suggestions: &SuggestionsRegistry,
+    context: &RenderContext,
+    cx: &mut WindowContext,
+) -> ComponentType {
+    // Outside dev mode the element is returned as is, without a wrapper
+    if !DevMode::enabled(cx) {
+        return render_element(
+            component,
+            views,
+            handlers,
+            bindings,
+            suggestions,
+            context,
+            cx,
+        );
+    }
+
+    let path = cx.default_global::<RenderProfile>().enter(component);
+    let start = Instant::now();
+    let element = render_element(
+        component,
+        views,
+        handlers,
+        bindings,
+        suggestions,
+        context,
+        cx,
+    );
+    cx.global_mut::<RenderProfile>().exit(start.elapsed());
+    ComponentType::Div(inspect(component, path, element.into_any_element()))
 }
 
-pub fn render_component(component: &Component) -> ComponentType {
+fn render_element(
+    component: &Component,
+    views: &mut ViewCache,
+    handlers: &HandlerRegistry,
+    bindings: &BindingRegistry,
+    suggestions: &SuggestionsRegistry,
+    context: &RenderContext,
+    cx: &mut WindowContext,
+) -> ComponentType {
     let component_id = ElementId::from(component.number);
+    // Theme colors of classes like bg-primary
+    let theme = Theme::current(cx);
 
     let element = match component.elem.as_str() {
-        "div" => {
+        "div" | "button" => {
+            let parent_pointer_events = PointerEvents::enabled(cx);
+            let pointer_events = PointerEvents::from_attributes(&component.attributes)
+                .unwrap_or(parent_pointer_events);
+
             let mut element = div().id(component_id.clone());
+            if component.elem == "button" && pointer_events {
+                element = element.cursor_pointer();
+            }
 
-            // Recursively render children and add them
-            if !component.children.is_empty() {
-                let children_elements = component.children.iter().map(render_component);
-                for child in children_elements {
-                    match child {
-                        ComponentType::Div(div) => element = element.child(div),
-                        ComponentType::Img(img) => element = element.child(img),
-                        ComponentType::Svg(svg) => element = element.child(svg),
-                        ComponentType::Input(input) => {
-                            // The nested match for handling different input types
-                            match input {
-                                Input::InputNumber(input_number) => {
-                                    element = element.child(input_number)
-                                }
-                                Input::InputText(input_text) => element = element.child(input_text),
-                                Input::InputCheckbox(input_checkbox) => {
-                                    element = element.child(input_checkbox)
-                                }
-                                Input::InputSelect(input_radio) => {
-                                    element = element.child(input_radio)
-                                }
-                            }
-                        }
-                    }
+            // Gradient is painted behind the children
+            if let Some(gradient) = gradient_from_attributes(&component.attributes) {
+                element = element.relative().child(gradient);
+            }
+
+            // Recursively render children and add them. They inherit pointer-events-none
+            cx.set_global(PointerEvents(pointer_events));
+            let spacing = ChildSpacing::from_attributes(&component.attributes);
+            let grid_columns = grid_columns(&component.attributes);
+            for (index, child) in component.children.iter().enumerate() {
+                let mut child_style = None;
+                // Like Tailwind, the first child gets no space before it
+                if let Some(spacing) = spacing.as_ref().filter(|_| index > 0) {
+                    child_style = Some(spacing.apply_margin(StyleRefinement::default()));
+                }
+                if let Some(columns) = grid_columns {
+                    let span = grid_span(child, columns);
+                    child_style = Some(
+                        child_style
+                            .unwrap_or_default()
+                            .flex_none()
+                            .w(relative(span as f32 / columns as f32)),
+                    );
                 }
+
+                let child =
+                    render_component(child, views, handlers, bindings, suggestions, context, cx);
+                element = match &child_style {
+                    Some(style) => element.child(style_child(child, style)),
+                    None => element.child(child.into_any_element()),
+                };
             }
+            cx.set_global(PointerEvents(parent_pointer_events));
 
             // Add text if exists
             if let Some(text) = &component.text {
-                element = element.child(text.clone());
+                element =
+                    element.child(align_text(context.interpolate(text), &component.attributes));
+            }
+
+            // Filters are drawn over the children. Relative is set before the classes, so an
+            // absolute class still wins
+            let overlays = Filter::from_attributes(&component.attributes).overlays("div");
+            if !overlays.is_empty() {
+                element = element.relative().children(overlays);
             }
 
-            let element = set_attributes(element, &component.attributes);
+            let element = set_attributes(element, &component.attributes, &theme);
             // Set stateful attributes to div
-            let element = set_stateful_element_attributes(element, &component.attributes);
+            let element = set_stateful_element_attributes(
+                element,
+                &component.attributes,
+                &theme,
+                pointer_events,
+            );
+            let mut element =
+                Transform::from_attributes(&component.attributes).apply_offset(element, "div");
+
+            // Route event attributes to the registered Rust handlers
+            for (event, name) in component.events.iter().filter(|_| pointer_events) {
+                match event.as_str() {
+                    "click" => {
+                        if let Some(modal_id) = name.strip_prefix("modal:") {
+                            let modals = views.modals.clone();
+                            let modal_id = modal_id.to_string();
+                            element = element.on_click(move |_event, cx| {
+                                let modal = modals.borrow().get(&modal_id).cloned();
+                                match modal {
+                                    Some(modal) => modal.update(cx, |modal, cx| modal.open(cx)),
+                                    None => tracing::warn!("No <modal id=\"{}\">", modal_id),
+                                }
+                            });
+                            continue;
+                        }
+                        if let Some(drawer_id) = name.strip_prefix("drawer:") {
+                            let drawers = views.drawers.clone();
+                            let drawer_id = drawer_id.to_string();
+                            element = element.on_click(move |_event, cx| {
+                                let drawer = drawers.borrow().get(&drawer_id).cloned();
+                                match drawer {
+                                    Some(drawer) => drawer.update(cx, |drawer, cx| drawer.open(cx)),
+                                    None => tracing::warn!("No <drawer id=\"{}\">", drawer_id),
+                                }
+                            });
+                            continue;
+                        }
+
+                        let handler = handlers.get(name);
+                        let name = name.clone();
+                        element = element.on_click(move |_event, cx| match &handler {
+                            Some(handler) => handler(cx),
+                            None => tracing::warn!("No handler registered for \"{}\"", name),
+                        });
+                    }
+                    _ => tracing::warn!("Unsupported event attribute \"on:{}\"", event),
+                }
+            }
 
             ComponentType::Div(element)
         }
         "img" => {
+            warn_unsupported_modifiers(component);
+
             // Get attribute "src"
             let src = component
                 .attributes
@@ -171,7 +782,17 @@ pub fn render_component(component: &Component) -> ComponentType {
 
             if let Some(src) = src {
                 let mut element = img(src);
-                element = set_attributes::<Img>(element, &component.attributes);
+                element = set_attributes::<Img>(element, &component.attributes, &theme);
+                element =
+                    Transform::from_attributes(&component.attributes).apply_offset(element, "img");
+                let filter = Filter::from_attributes(&component.attributes);
+                if filter.grayscale {
+                    element = element.grayscale(true);
+                }
+                filter.warn_unsupported("img", false);
+                if let Some(object_fit) = object_fit_from_attributes(&component.attributes) {
+                    element = element.object_fit(object_fit);
+                }
                 ComponentType::Img(element)
             } else {
                 ComponentType::Div(
@@ -182,6 +803,8 @@ pub fn render_component(component: &Component) -> ComponentType {
             }
         }
         "svg" => {
+            warn_unsupported_modifiers(component);
+
             // Get attribute "src"
             let path = component
                 .attributes
@@ -191,7 +814,12 @@ pub fn render_component(component: &Component) -> ComponentType {
 
             if let Some(path) = path {
                 let mut element = svg().path(path);
-                element = set_attributes::<Svg>(element, &component.attributes);
+                element = set_attributes::<Svg>(element, &component.attributes, &theme);
+                let transform = Transform::from_attributes(&component.attributes);
+                if !transform.is_empty() {
+                    element =
+                        element.with_transformation(transform.to_transformation(cx.rem_size()));
+                }
                 ComponentType::Svg(element)
             } else {
                 ComponentType::Div(
@@ -202,9 +830,7 @@ pub fn render_component(component: &Component) -> ComponentType {
             }
         }
         "input" => {
-            // let mut element = InputText::new();
-            // element = set_attributes::<InputText>(element, &component.attributes);
-            // ComponentType::InputText(element)
+            warn_unsupported_modifiers(component);
 
             // Create correct input based on the "type" attribute
             let input_type = component
@@ -216,123 +842,1255 @@ pub fn render_component(component: &Component) -> ComponentType {
             match input_type {
                 Some(input_type) => match input_type.as_str() {
                     "number" => {
-                        let mut element = input::number::InputNumber::new();
-                        element = set_attributes::<input::number::InputNumber>(
-                            element,
-                            &component.attributes,
-                        );
-                        ComponentType::Input(Input::InputNumber(element))
+                        let attribute = |key: &str| {
+                            component
+                                .attributes
+                                .iter()
+                                .find(|(k, _)| k == key)
+                                .map(|(_, v)| v.as_str())
+                        };
+                        let number_attribute =
+                            |key: &str| attribute(key).and_then(|v| v.parse::<f64>().ok());
+
+                        // value, min and max are in the internal unit, conversion only affects what is shown
+                        let unit = match (
+                            attribute("display-unit"),
+                            number_attribute("conversion-factor"),
+                        ) {
+                            (Some(display_unit), Some(factor)) if factor != 0.0 => {
+                                Some(input::number::UnitConversion::new(
+                                    display_unit.to_string(),
+                                    attribute("internal-unit").unwrap_or_default().to_string(),
+                                    factor,
+                                ))
+                            }
+                            (Some(_), _) => {
+                                tracing::warn!("display-unit needs a non-zero conversion-factor");
+                                None
+                            }
+                            _ => None,
+                        };
+                        let value = number_attribute("value").unwrap_or_default();
+                        let min = number_attribute("min");
+                        let max = number_attribute("max");
+
+                        let view = views.get_or_insert(component.number, cx, |cx| {
+                            let mut element = input::number::InputNumber::new(cx).value(value);
+                            if let Some(min) = min {
+                                element = element.min(min);
+                            }
+                            if let Some(max) = max {
+                                element = element.max(max);
+                            }
+                            if let Some(unit) = unit {
+                                element = element.unit(unit);
+                            }
+                            set_attributes::<input::number::InputNumber>(
+                                element,
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        ComponentType::Input(view.into_any_element())
                     }
                     "text" => {
-                        let mut element = input::text::InputText::new();
-                        element = set_attributes::<input::text::InputText>(
-                            element,
-                            &component.attributes,
-                        );
-                        ComponentType::Input(Input::InputText(element))
+                        let placeholder = component
+                            .attributes
+                            .iter()
+                            .find(|(k, _)| k == "placeholder")
+                            .map(|(_, v)| v.clone())
+                            .unwrap_or_default();
+                        let value = component
+                            .attributes
+                            .iter()
+                            .find(|(k, _)| k == "value")
+                            .map(|(_, v)| v.clone())
+                            .unwrap_or_default();
+                        let binding = bound_model(component, bindings);
+                        let view = views.get_or_insert(component.number, cx, |cx| {
+                            let mut element = input::text::InputText::new(cx)
+                                .placeholder(placeholder)
+                                .value(value);
+                            if let Some(model) = binding {
+                                element = element.bind(model, cx);
+                            }
+                            set_attributes::<input::text::InputText>(
+                                element,
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        ComponentType::Input(view.into_any_element())
+                    }
+                    "autocomplete" => {
+                        let attribute = |key: &str| {
+                            component
+                                .attributes
+                                .iter()
+                                .find(|(k, _)| k == key)
+                                .map(|(_, v)| v.clone())
+                        };
+                        let placeholder = attribute("placeholder").unwrap_or_default();
+                        let value = attribute("value").unwrap_or_default();
+                        let provider = attribute("suggestions").and_then(|name| {
+                            let provider = suggestions.get(&name);
+                            if provider.is_none() {
+                                tracing::warn!("No suggestions registered for \"{}\"", name);
+                            }
+                            provider
+                        });
+                        let binding = bound_model(component, bindings);
+                        let view = views.get_or_insert(component.number, cx, |cx| {
+                            let mut element = input::autocomplete::InputAutoComplete::new(cx)
+                                .placeholder(placeholder)
+                                .value(value);
+                            if let Some(provider) = provider {
+                                element = element.suggestions_provider(provider);
+                            }
+                            if let Some(model) = binding {
+                                element = element.bind(model, cx);
+                            }
+                            set_attributes::<input::autocomplete::InputAutoComplete>(
+                                element,
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        ComponentType::Input(view.into_any_element())
                     }
                     "checkbox" => {
-                        let mut element = input::checkbox::InputCheckbox::new();
-                        element = set_attributes::<input::checkbox::InputCheckbox>(
-                            element,
-                            &component.attributes,
-                        );
-                        ComponentType::Input(Input::InputCheckbox(element))
+                        let checked = component
+                            .attributes
+                            .iter()
+                            .any(|(k, v)| k == "checked" && v == "true");
+                        let view = views.get_or_insert(component.number, cx, |_cx| {
+                            set_attributes::<input::checkbox::InputCheckbox>(
+                                input::checkbox::InputCheckbox::new().checked(checked),
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        ComponentType::Input(view.into_any_element())
+                    }
+                    "toggle" => {
+                        let is_true = |key: &str| {
+                            component
+                                .attributes
+                                .iter()
+                                .any(|(k, v)| k == key && v == "true")
+                        };
+                        let checked = is_true("checked");
+                        let disabled = is_true("disabled");
+                        let binding = bound_model(component, bindings);
+                        let view = views.get_or_insert(component.number, cx, |cx| {
+                            let mut element = input::toggle::InputToggle::new()
+                                .checked(checked)
+                                .disabled(disabled);
+                            if let Some(model) = binding {
+                                element = element.bind(model, cx);
+                            }
+                            set_attributes::<input::toggle::InputToggle>(
+                                element,
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        ComponentType::Input(view.into_any_element())
                     }
                     "select" => {
-                        let mut element = input::select::InputSelect::new();
-                        element = set_attributes::<input::select::InputSelect>(
-                            element,
-                            &component.attributes,
+                        // Options come either from the "options" attribute or <option> children
+                        let mut options = component
+                            .attributes
+                            .iter()
+                            .find(|(k, _)| k == "options")
+                            .map(|(_, v)| input::select::parse_options(v))
+                            .unwrap_or_default();
+                        options.extend(
+                            component
+                                .children
+                                .iter()
+                                .filter(|child| child.elem == "option")
+                                .map(|child| {
+                                    let value = child
+                                        .attributes
+                                        .iter()
+                                        .find(|(k, _)| k == "value")
+                                        .map(|(_, v)| v.clone())
+                                        .or_else(|| child.text.clone())
+                                        .unwrap_or_default();
+                                    let label = child.text.clone().unwrap_or(value.clone());
+                                    input::select::SelectOption::new(value, label)
+                                }),
                         );
-                        ComponentType::Input(Input::InputSelect(element))
+                        let selected = component
+                            .attributes
+                            .iter()
+                            .find(|(k, _)| k == "value" || k == "default_value")
+                            .map(|(_, v)| v.clone());
+
+                        let view = views.get_or_insert(component.number, cx, |cx| {
+                            let mut element = input::select::InputSelect::new(cx);
+                            if let Some(selected) = selected {
+                                element = element.selected(selected);
+                            }
+                            set_attributes::<input::select::InputSelect>(
+                                element,
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        // Cached view keeps the old options when the file is reloaded
+                        view.update(cx, |select, cx| select.set_options(options, cx));
+                        ComponentType::Input(view.into_any_element())
+                    }
+                    "range" => {
+                        let number_attribute = |key: &str| {
+                            component
+                                .attributes
+                                .iter()
+                                .find(|(k, _)| k == key)
+                                .and_then(|(_, v)| v.parse::<f64>().ok())
+                        };
+                        let min = number_attribute("min").unwrap_or(0.0);
+                        let max = number_attribute("max").unwrap_or(100.0);
+                        let value_min = number_attribute("value-min").unwrap_or(min);
+                        let value_max = number_attribute("value-max").unwrap_or(max);
+                        let step = number_attribute("step");
+                        // value (or bind) makes a single value slider instead of a min/max pair
+                        let value = number_attribute("value");
+                        let binding = bound_model(component, bindings);
+
+                        let view = views.get_or_insert(component.number, cx, |cx| {
+                            let mut element = input::range::InputRange::new(min, max);
+                            if let Some(step) = step {
+                                element = element.step(step);
+                            }
+                            element = match (value, binding) {
+                                (value, Some(model)) => {
+                                    element.value(value.unwrap_or(min)).bind(model, cx)
+                                }
+                                (Some(value), None) => element.value(value),
+                                (None, None) => element.value_max(value_max).value_min(value_min),
+                            };
+                            set_attributes::<input::range::InputRange>(
+                                element,
+                                &component.attributes,
+                                &theme,
+                            )
+                        });
+                        ComponentType::Input(view.into_any_element())
                     }
-                    _ => ComponentType::Div(div().id(component_id)),
+                    _ => ComponentType::Div(
+                        div()
+                            .id(component_id)
+                            .child(format!("Error: unknown input type \"{}\"", input_type)),
+                    ),
                 },
-                _ => ComponentType::Div(div().id(component_id)),
+                _ => ComponentType::Div(
+                    div()
+                        .id(component_id)
+                        .child("Error: input element must have type attribute"),
+                ),
             }
         }
-        _ => ComponentType::Div(div().id(component_id)),
-    };
-
-    element
-}
+        "tag-input" => {
+            warn_unsupported_modifiers(component);
 
-// Convert #RRGGBB to rgb(0x000000) format where 0x000000 is the hex value of the color in integer
-// rgb is function call to convert hex to rgb
-fn hex_to_rgba(hex: &str) -> Rgba {
-    let hex = hex.trim_start_matches('#');
-    let r = u32::from_str_radix(&hex[0..2], 16).unwrap();
-    let g = u32::from_str_radix(&hex[2..4], 16).unwrap();
-    let b = u32::from_str_radix(&hex[4..6], 16).unwrap();
-    // Get also the alpha channel if it exists
-    let a = if hex.len() == 8 {
-        u32::from_str_radix(&hex[6..8], 16).unwrap()
-    } else {
-        255
-    };
-    // u32 is the hex value of the color with alpha
-    let value = (r << 24) | (g << 16) | (b << 8) | a;
-    rgba(value)
-}
+            let attribute = |key: &str| {
+                component
+                    .attributes
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v.as_str())
+            };
+            let tags: Vec<String> = attribute("value")
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default();
+            let min_tags = attribute("min-tags").and_then(|v| v.parse::<usize>().ok());
+            let max_tags = attribute("max-tags").and_then(|v| v.parse::<usize>().ok());
+            // Like the HTML pattern attribute, the whole tag must match
+            let validator = attribute("validator").and_then(|pattern| {
+                regex::Regex::new(&format!("^(?:{})$", pattern))
+                    .map_err(|e| {
+                        tracing::warn!("Invalid tag-input validator \"{}\": {}", pattern, e)
+                    })
+                    .ok()
+            });
 
-fn set_stateful_element_attributes<T: StatefulInteractiveElement>(
-    mut element: T,
-    attributes: &Vec<(String, String)>,
-) -> T {
-    // Class attribute
-    if let Some(class_attr_value) = attributes
-        .iter()
-        .find(|(k, _)| k == "class")
-        .map(|(_, v)| v)
-    {
-        // Split the class attribute into individual classes
-        let classes = class_attr_value.split_whitespace();
-        for class_name in classes {
-            element = tailwind_to_gpui!(element, class_name,
-                [ "overflow-scroll", "overflow-x-scroll", "overflow-y-scroll" ],
-                _ => {
-                    element
+            let view = views.get_or_insert(component.number, cx, |cx| {
+                let mut element = input::tag_list::InputTagList::new(cx).tags(tags);
+                if let Some(min_tags) = min_tags {
+                    element = element.min_tags(min_tags);
                 }
-            );
+                if let Some(max_tags) = max_tags {
+                    element = element.max_tags(max_tags);
+                }
+                if let Some(validator) = validator {
+                    element = element.validator(validator);
+                }
+                set_attributes::<input::tag_list::InputTagList>(
+                    element,
+                    &component.attributes,
+                    &theme,
+                )
+            });
+            ComponentType::Input(view.into_any_element())
         }
-    }
-    element
-}
+        "table" => {
+            warn_unsupported_modifiers(component);
 
-fn set_attributes<T: Styled>(mut element: T, attributes: &Vec<(String, String)>) -> T {
-    // Custom attributes that has some default styles
-    if let Some(type_value) = attributes
-        .iter()
-        .find(|(k, _)| k == "type")
-        .map(|(_, v)| v.as_str())
-    {
-        match type_value {
-            "maintable" => {
-                element = element
-                    .flex()
-                    .flex_col()
-                    .p_1()
-                    .size_full()
-                    .border_1()
-                    .border_color(rgb(0x000000));
+            let cell_text = |cell: &Component| {
+                cell.text
+                    .as_deref()
+                    .map(|text| context.interpolate(text))
+                    .unwrap_or_default()
+            };
+
+            // <th>s in <thead> are the columns, <td>s in <tbody> the rows
+            let mut columns = Vec::new();
+            let mut rows = Vec::new();
+            for section in &component.children {
+                match section.elem.as_str() {
+                    "thead" => {
+                        for row in section.children.iter().filter(|c| c.elem == "tr") {
+                            columns.extend(row.children.iter().filter(|c| c.elem == "th").map(
+                                |th| {
+                                    let column = ColumnDef::new(cell_text(th));
+                                    match th.attributes.iter().find(|(k, _)| k == "width") {
+                                        // Same lengths as in the classes, e.g. "120px" or "[8rem]"
+                                        Some((_, width)) => {
+                                            column.width(extract_length_from_class_name(width))
+                                        }
+                                        None => column,
+                                    }
+                                },
+                            ));
+                        }
+                    }
+                    "tbody" => {
+                        for row in section.children.iter().filter(|c| c.elem == "tr") {
+                            rows.push(
+                                row.children
+                                    .iter()
+                                    .filter(|c| c.elem == "td")
+                                    .map(cell_text)
+                                    .collect(),
+                            );
+                        }
+                    }
+                    other => tracing::warn!("Unsupported <{}> inside <table>", other),
+                }
             }
-            "subtable" => {
-                element = element
-                    .flex()
-                    .p_1()
-                    .size_full()
-                    .border_1()
-                    .border_color(rgb(0x0000ff));
+
+            ComponentType::Table(set_attributes(
+                Table::new(columns, rows),
+                &component.attributes,
+                &theme,
+            ))
+        }
+        "tabs" => {
+            warn_unsupported_modifiers(component);
+
+            let tabs: Vec<TabDef> = component
+                .children
+                .iter()
+                .filter(|child| child.elem == "tab")
+                .map(|tab| TabDef {
+                    label: tab
+                        .attributes
+                        .iter()
+                        .find(|(k, _)| k == "label")
+                        .map(|(_, v)| context.interpolate(v))
+                        .unwrap_or_default(),
+                    body: tab.children.clone(),
+                })
+                .collect();
+
+            let view = views.get_or_insert(component.number, cx, |_cx| {
+                set_attributes(Tabs::new(tabs.clone()), &component.attributes, &theme)
+            });
+            view.update(cx, |view, cx| {
+                // Cached view keeps the old labels when the context changes
+                view.set_tabs(tabs, cx);
+                view.set_registries(views, handlers, bindings, suggestions, context)
+            });
+            ComponentType::Tabs(view)
+        }
+        "accordion" => {
+            warn_unsupported_modifiers(component);
+
+            let mut sections = Vec::new();
+            for section in &component.children {
+                if section.elem != "section" {
+                    tracing::warn!("Unsupported <{}> inside <accordion>", section.elem);
+                    continue;
+                }
+                let attribute = |key: &str| {
+                    section
+                        .attributes
+                        .iter()
+                        .find(|(k, _)| k == key)
+                        .map(|(_, v)| v.as_str())
+                };
+                let title = context.interpolate(attribute("title").unwrap_or_default());
+                let default_open = attribute("default-open") == Some("true");
+
+                let view = views.get_or_insert(section.number, cx, |_cx| {
+                    set_attributes(
+                        AccordionSection::new(title, section.children.clone())
+                            .expanded(default_open),
+                        &section.attributes,
+                        &theme,
+                    )
+                });
+                view.update(cx, |section, _cx| {
+                    section.set_registries(views, handlers, bindings, suggestions, context)
+                });
+                sections.push(view);
             }
-            _ => {}
+            ComponentType::Accordion(sections)
         }
+        "modal" => {
+            warn_unsupported_modifiers(component);
+
+            let attribute = |key: &str| component_attribute(component, context, key);
+            let Some(id) = attribute("id") else {
+                return ComponentType::Div(
+                    div()
+                        .id(component_id)
+                        .child("Error: modal element must have id attribute"),
+                );
+            };
+            // on:confirm and on:cancel run the registered handlers when the modal is closed
+            for (event, _) in &component.events {
+                if event != "confirm" && event != "cancel" {
+                    tracing::warn!("Unsupported event attribute \"on:{}\" on <modal>", event);
+                }
+            }
+
+            let title = attribute("title").unwrap_or_default();
+            let body = attribute("body")
+                .or_else(|| component.text.as_deref().map(|t| context.interpolate(t)))
+                .unwrap_or_default();
+
+            let view = views.get_or_insert(component.number, cx, |cx| {
+                let mut modal = Modal::new(cx);
+                if let Some(on_confirm) = event_handler(component, handlers, "confirm") {
+                    modal = modal.on_confirm(on_confirm);
+                }
+                if let Some(on_cancel) = event_handler(component, handlers, "cancel") {
+                    modal = modal.on_cancel(on_cancel);
+                }
+                set_attributes(modal, &component.attributes, &theme)
+            });
+            // Cached view keeps the old texts when the context changes
+            view.update(cx, |modal, cx| modal.set_text(title, body, cx));
+            views.modals.borrow_mut().insert(id, view.clone());
+            ComponentType::Modal(view)
+        }
+        "drawer" => {
+            warn_unsupported_modifiers(component);
+
+            let attribute = |key: &str| component_attribute(component, context, key);
+            let title = attribute("title").unwrap_or_default();
+            let side = attribute("side")
+                .and_then(|value| {
+                    let side = DrawerSide::parse(&value);
+                    if side.is_none() {
+                        tracing::warn!("Unknown drawer side \"{}\"", value);
+                    }
+                    side
+                })
+                .unwrap_or_default();
+            let open = attribute("open").as_deref() == Some("true");
+
+            let view = views.get_or_insert(component.number, cx, |_cx| {
+                let mut drawer = Drawer::new(component.children.clone()).open_at_start(open);
+                if let Some(on_close) = event_handler(component, handlers, "close") {
+                    drawer = drawer.on_close(on_close);
+                }
+                set_attributes(drawer, &component.attributes, &theme)
+            });
+            view.update(cx, |drawer, cx| {
+                // Cached view keeps the old attributes when the context changes
+                drawer.update_attributes(title, side, open, cx);
+                drawer.set_registries(views, handlers, bindings, suggestions, context)
+            });
+            // Without an id the drawer can only be opened with open="true"
+            if let Some(id) = attribute("id") {
+                views.drawers.borrow_mut().insert(id, view.clone());
+            }
+            ComponentType::Drawer(view)
+        }
+        "progress" => {
+            warn_unsupported_modifiers(component);
+
+            // Values can come from the context, e.g. value="{upload_progress}"
+            let attribute = |key: &str| component_attribute(component, context, key);
+            let number = |key: &str| attribute(key).and_then(|v| v.trim().parse::<f32>().ok());
+            let value = number("value").unwrap_or(0.0);
+            let max = number("max");
+            let color = attribute("color").and_then(|color| {
+                let hex = color.trim_start_matches('#');
+                if matches!(hex.len(), 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    Some(hex_to_rgba(hex))
+                } else {
+                    tracing::warn!("Invalid progress color \"{}\"", color);
+                    None
+                }
+            });
+            let on_complete = event_handler(component, handlers, "complete");
+
+            let view = views.get_or_insert(component.number, cx, |_cx| {
+                let mut element = ProgressBar::new().value(value);
+                if let Some(max) = max {
+                    element = element.max(max);
+                }
+                if let Some(color) = color {
+                    element = element.color(color);
+                }
+                if let Some(on_complete) = on_complete {
+                    element = element.on_complete(on_complete);
+                }
+                set_attributes(element, &component.attributes, &theme)
+            });
+            // Cached view keeps the old value when the context changes
+            view.update(cx, |progress, cx| progress.set_value(value, cx));
+            ComponentType::ProgressBar(view)
+        }
+        "alert" => {
+            warn_unsupported_modifiers(component);
+
+            let attribute = |key: &str| component_attribute(component, context, key);
+            let severity = attribute("severity")
+                .and_then(|value| {
+                    let severity = alert::Severity::parse(&value);
+                    if severity.is_none() {
+                        tracing::warn!("Unknown alert severity \"{}\"", value);
+                    }
+                    severity
+                })
+                .unwrap_or_default();
+            // Longer messages can be written as a <text> child instead of the attribute
+            let message = attribute("message")
+                .or_else(|| {
+                    component
+                        .children
+                        .iter()
+                        .find(|child| child.elem == "text")
+                        .and_then(|child| child.text.as_deref())
+                        .map(|message| context.interpolate(message))
+                })
+                .unwrap_or_default();
+            let dismissible = attribute("dismissible").as_deref() == Some("true");
+
+            let view = views.get_or_insert(component.number, cx, |_cx| {
+                set_attributes(
+                    Alert::new(severity, message.clone()).dismissible(dismissible),
+                    &component.attributes,
+                    &theme,
+                )
+            });
+            view.update(cx, |alert, cx| alert.set_message(message, cx));
+            ComponentType::Alert(view)
+        }
+        "spinner" => {
+            warn_unsupported_modifiers(component);
+
+            let attribute = |key: &str| {
+                component
+                    .attributes
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v.as_str())
+            };
+            let size = attribute("size")
+                .and_then(|value| {
+                    let size = SpinnerSize::parse(value);
+                    if size.is_none() {
+                        tracing::warn!("Unknown spinner size \"{}\"", value);
+                    }
+                    size
+                })
+                .unwrap_or_default();
+
+            let view = views.get_or_insert(component.number, cx, |cx| {
+                set_attributes(Spinner::new(cx).size(size), &component.attributes, &theme)
+            });
+            ComponentType::Spinner(view)
+        }
+        "breadcrumb" => {
+            warn_unsupported_modifiers(component);
+
+            let crumbs = component
+                .children
+                .iter()
+                .filter(|child| child.elem == "crumb")
+                .map(|child| {
+                    let label = component_attribute(child, context, "label").unwrap_or_default();
+                    let crumb = Crumb::new(label);
+                    match event_handler(child, handlers, "click") {
+                        Some(handler) => crumb.on_click(handler),
+                        None => crumb,
+                    }
+                })
+                .collect();
+
+            ComponentType::Breadcrumb(set_attributes(
+                Breadcrumb::new(crumbs),
+                &component.attributes,
+                &theme,
+            ))
+        }
+        "pagination" => {
+            warn_unsupported_modifiers(component);
+
+            // Values can come from the context, e.g. total="{parameter_count}"
+            let attribute = |key: &str| component_attribute(component, context, key);
+            let number = |key: &str| attribute(key).and_then(|v| v.trim().parse::<usize>().ok());
+            let total = number("total").unwrap_or(0);
+            let page_size = number("page-size").unwrap_or(10);
+            let current_page = number("current-page").unwrap_or(1);
+            let compact = attribute("compact").as_deref() == Some("true");
+            let on_change = event_handler(component, handlers, "change");
+            let binding = bound_model(component, bindings);
+
+            let view = views.get_or_insert(component.number, cx, |_cx| {
+                let mut element = Pagination::new(total, page_size)
+                    .current_page(current_page)
+                    .compact(compact);
+                if let Some(on_change) = on_change {
+                    element = element.on_change(on_change);
+                }
+                if let Some(model) = binding {
+                    element = element.bind(model);
+                }
+                set_attributes(element, &component.attributes, &theme)
+            });
+            view.update(cx, |pagination, cx| pagination.set_total_items(total, cx));
+            ComponentType::Pagination(view)
+        }
+        _ => ComponentType::Div(div().id(component_id)),
+    };
+
+    // Any element can have a tooltip="...", the tooltip wraps the rendered element
+    let Some((_, text)) = component.attributes.iter().find(|(k, _)| k == "tooltip") else {
+        return element;
+    };
+    let position = component
+        .attributes
+        .iter()
+        .find(|(k, _)| k == "tooltip-position")
+        .and_then(|(_, value)| {
+            let position = TooltipPosition::parse(value);
+            if position.is_none() {
+                tracing::warn!("Unknown tooltip-position \"{}\"", value);
+            }
+            position
+        })
+        .unwrap_or_default();
+    ComponentType::Tooltip(
+        Tooltip::new(
+            component.number,
+            element.into_any_element(),
+            context.interpolate(text),
+        )
+        .position(position),
+    )
+}
+
+// bind="key" keeps an input's value in sync with the model registered under that key
+fn bound_model(component: &Component, bindings: &BindingRegistry) -> Option<Model<String>> {
+    let (_, key) = component.attributes.iter().find(|(k, _)| k == "bind")?;
+    let model = bindings.get(key);
+    if model.is_none() {
+        tracing::warn!("No model bound to \"{}\"", key);
     }
-    // Font attribute
-    if let Some(font_attr_value) = attributes.iter().find(|(k, _)| k == "font").map(|(_, v)| v) {
-        let font: SharedString = SharedString::from(font_attr_value.clone());
-        element = element.font(font);
+    model
+}
+
+// Attribute value with {key} placeholders filled in from the context
+fn component_attribute(
+    component: &Component,
+    context: &RenderContext,
+    key: &str,
+) -> Option<String> {
+    let (_, value) = component.attributes.iter().find(|(k, _)| k == key)?;
+    Some(context.interpolate(value))
+}
+
+// Registered handler of an on:<event> attribute
+fn event_handler(
+    component: &Component,
+    handlers: &HandlerRegistry,
+    event: &str,
+) -> Option<Handler> {
+    let (_, name) = component.events.iter().find(|(e, _)| e == event)?;
+    let handler = handlers.get(name);
+    if handler.is_none() {
+        tracing::warn!("No handler registered for \"{}\"", name);
+    }
+    handler
+}
+
+// GPUI has no text-align style, so text-left/center/right wrap the text into a full width
+// flex row that is justified accordingly
+fn align_text(text: String, attributes: &Vec<(String, String)>) -> AnyElement {
+    let alignment = attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .and_then(|(_, v)| {
+            v.split_whitespace().find(|class_name| {
+                matches!(
+                    *class_name,
+                    "text-left" | "text-center" | "text-right" | "text-justify"
+                )
+            })
+        });
+
+    let wrapper = div().flex().w_full();
+    match alignment {
+        Some("text-left") => wrapper.justify_start().child(text).into_any_element(),
+        Some("text-center") => wrapper.justify_center().child(text).into_any_element(),
+        Some("text-right") => wrapper.justify_end().child(text).into_any_element(),
+        Some(class_name) => {
+            tracing::warn!("{} is not supported by GPUI", class_name);
+            text.into_any_element()
+        }
+        None => text.into_any_element(),
+    }
+}
+
+// Converts RRGGBB or RRGGBBAA, with or without the #, to a color. Anything else is logged and
+// gives transparent, so a typo in a class doesn't take the app down
+fn hex_to_rgba(hex: &str) -> Rgba {
+    let hex = hex.trim_start_matches('#');
+    // from_str_radix would also accept a leading +
+    let value = u32::from_str_radix(hex, 16)
+        .ok()
+        .filter(|_| hex.chars().all(|c| c.is_ascii_hexdigit()));
+    match (hex.len(), value) {
+        (6, Some(value)) => rgba((value << 8) | 0xff),
+        (8, Some(value)) => rgba(value),
+        _ => {
+            tracing::warn!("Invalid hex color \"{}\", using transparent", hex);
+            rgba(0x00000000)
+        }
+    }
+}
+
+// Sets a style render_component computed for a child, e.g. the space-x-N margin
+fn style_child(child: ComponentType, style: &StyleRefinement) -> AnyElement {
+    fn refined<T: Styled>(mut element: T, style: &StyleRefinement) -> T {
+        element.style().refine(style);
+        element
+    }
+
+    match child {
+        ComponentType::Div(div) => refined(div, style).into_any_element(),
+        ComponentType::Img(img) => refined(img, style).into_any_element(),
+        ComponentType::Svg(svg) => refined(svg, style).into_any_element(),
+        ComponentType::Table(table) => refined(table, style).into_any_element(),
+        ComponentType::Breadcrumb(breadcrumb) => refined(breadcrumb, style).into_any_element(),
+        // Views can't be styled from the outside, so they get a wrapper with the style
+        child => refined(div().flex().child(child.into_any_element()), style).into_any_element(),
+    }
+}
+
+// GPUI has no CSS grid yet, so a grid is a wrapping flex row where every child gets the
+// width of the columns it spans. Only grid-cols-N on a grid div is supported
+fn grid_columns(attributes: &[(String, String)]) -> Option<u16> {
+    let classes = attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .map(|(_, v)| v)?;
+    if !classes
+        .split_whitespace()
+        .any(|class_name| class_name == "grid")
+    {
+        return None;
+    }
+
+    classes.split_whitespace().find_map(|class_name| {
+        class_name
+            .strip_prefix("grid-cols-")
+            .and_then(parse_columns)
+    })
+}
+
+// grid-cols-1 to grid-cols-12
+fn parse_columns(value: &str) -> Option<u16> {
+    value
+        .parse::<u16>()
+        .ok()
+        .filter(|columns| (1..=12).contains(columns))
+}
+
+// col-span-N, or col-span-full which spans as many columns as there are
+fn parse_span(value: &str) -> Option<u16> {
+    if value == "full" {
+        return Some(u16::MAX);
+    }
+    value.parse::<u16>().ok().filter(|span| *span > 0)
+}
+
+// Columns the child spans with col-span-N, at most all of them
+fn grid_span(child: &Component, columns: u16) -> u16 {
+    let classes = child
+        .attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .map(|(_, v)| v.as_str())
+        .unwrap_or_default();
+
+    let mut span = 1;
+    for class_name in classes.split_whitespace() {
+        if let Some(value) = class_name.strip_prefix("col-span-") {
+            span = parse_span(value).unwrap_or(1);
+        } else if class_name.starts_with("row-span-") {
+            tracing::warn!(
+                "{} not yet supported by GPUI, grid rows are as high as their content",
+                class_name
+            );
+        }
+    }
+    span.clamp(1, columns)
+}
+
+// bg-gradient-to-r from-[#hex] via-[#hex] to-[#hex]. The colors can also be named, e.g.
+// from-blue-500. Without a from- or to- color the gradient fades from/to transparent
+fn gradient_from_attributes(attributes: &[(String, String)]) -> Option<Gradient> {
+    let classes = attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .map(|(_, v)| v)?;
+
+    let mut direction = None;
+    let (mut from, mut via, mut to) = (None, None, None);
+    for class_name in classes.split_whitespace() {
+        if let Some(value) = class_name.strip_prefix("bg-gradient-to-") {
+            direction = GradientDirection::parse(value);
+        } else if let Some(value) = class_name.strip_prefix("from-") {
+            from = gradient_color(value);
+        } else if let Some(value) = class_name.strip_prefix("via-") {
+            via = gradient_color(value);
+        } else if let Some(value) = class_name.strip_prefix("to-") {
+            to = gradient_color(value);
+        }
+    }
+
+    let transparent = rgba(0x00000000);
+    let gradient = Gradient::new(
+        direction?,
+        from.unwrap_or(transparent),
+        to.unwrap_or(transparent),
+    );
+    Some(match via {
+        Some(via) => gradient.via(via),
+        None => gradient,
+    })
+}
+
+// Color of from-, via- and to-, e.g. "[#0055ff]" or "blue-500"
+fn gradient_color(value: &str) -> Option<Rgba> {
+    match value.strip_prefix("[#") {
+        Some(hex) => Some(hex_to_rgba(hex.trim_end_matches(']'))),
+        None => tailwind_color(value),
+    }
+}
+
+// Filter classes: blur, brightness, contrast, grayscale, sepia and invert. GPUI has no filters,
+// only grayscale on images. On divs brightness is approximated with a black or white overlay
+// and grayscale with a gray one. The rest only log a warning
+#[derive(Default)]
+struct Filter {
+    blur: Option<f32>,
+    brightness: Option<f32>,
+    contrast: Option<f32>,
+    grayscale: bool,
+    sepia: bool,
+    invert: bool,
+}
+
+impl Filter {
+    fn from_attributes(attributes: &[(String, String)]) -> Self {
+        let mut filter = Filter::default();
+        let Some(classes) = attributes
+            .iter()
+            .find(|(k, _)| k == "class")
+            .map(|(_, v)| v)
+        else {
+            return filter;
+        };
+
+        for class_name in classes.split_whitespace() {
+            filter.apply_class(class_name);
+        }
+        filter
+    }
+
+    // None when the class isn't a filter class or its value doesn't parse
+    fn apply_class(&mut self, class_name: &str) -> Option<()> {
+        match class_name {
+            "blur-sm" => self.blur = Some(4.0),
+            "blur" | "blur-md" => self.blur = Some(8.0),
+            "blur-lg" => self.blur = Some(16.0),
+            "blur-none" => self.blur = None,
+            "grayscale" => self.grayscale = true,
+            "grayscale-0" => self.grayscale = false,
+            "sepia" => self.sepia = true,
+            "sepia-0" => self.sepia = false,
+            "invert" => self.invert = true,
+            "invert-0" => self.invert = false,
+            _ => {
+                if let Some(value) = class_name.strip_prefix("blur-[") {
+                    self.blur = Some(value.strip_suffix("px]")?.parse::<f32>().ok()?);
+                } else if let Some(value) = class_name.strip_prefix("brightness-") {
+                    self.brightness = Some(parse_amount(value)?);
+                } else if let Some(value) = class_name.strip_prefix("contrast-") {
+                    self.contrast = Some(parse_amount(value)?);
+                } else {
+                    return None;
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn overlays(&self, elem: &str) -> Vec<Div> {
+        self.warn_unsupported(elem, true);
+
+        let overlay = || div().absolute().top_0().left_0().size_full();
+        let mut overlays = Vec::new();
+        if self.grayscale {
+            overlays.push(overlay().bg(hsla(0.0, 0.0, 0.5, 0.5)));
+        }
+        match self.brightness {
+            Some(brightness) if brightness < 1.0 => {
+                overlays.push(overlay().bg(hsla(0.0, 0.0, 0.0, 1.0 - brightness.max(0.0))));
+            }
+            Some(brightness) if brightness > 1.0 => {
+                overlays.push(overlay().bg(hsla(0.0, 0.0, 1.0, (brightness - 1.0).min(1.0))));
+            }
+            _ => (),
+        }
+        overlays
+    }
+
+    // approximated tells if brightness and grayscale were drawn with overlays
+    fn warn_unsupported(&self, elem: &str, approximated: bool) {
+        let mut unsupported = Vec::new();
+        if self.blur.is_some() {
+            unsupported.push("blur");
+        }
+        if self.contrast.is_some() {
+            unsupported.push("contrast");
+        }
+        if self.sepia {
+            unsupported.push("sepia");
+        }
+        if self.invert {
+            unsupported.push("invert");
+        }
+        if !approximated && self.brightness.is_some() {
+            unsupported.push("brightness");
+        }
+        for filter in unsupported {
+            tracing::warn!("{} filter not yet supported by GPUI on <{}>", filter, elem);
+        }
+    }
+}
+
+// Transform classes: scale-[1.5], rotate-[45deg], translate-x-[12px] and translate-y-4, also
+// negated like -rotate-[15deg]. GPUI can transform only svgs. On other elements translate is
+// approximated by offsetting the element from where it would be, and scale and rotate are ignored
+#[derive(Default)]
+struct Transform {
+    scale: Option<f32>,
+    // Radians
+    rotate: Option<f32>,
+    translate_x: Option<AbsoluteLength>,
+    translate_y: Option<AbsoluteLength>,
+}
+
+impl Transform {
+    fn from_attributes(attributes: &[(String, String)]) -> Self {
+        let mut transform = Transform::default();
+        let Some(classes) = attributes
+            .iter()
+            .find(|(k, _)| k == "class")
+            .map(|(_, v)| v)
+        else {
+            return transform;
+        };
+
+        for class_name in classes.split_whitespace() {
+            transform.apply_class(class_name);
+        }
+        transform
+    }
+
+    // None when the class isn't a transform class or its value doesn't parse
+    fn apply_class(&mut self, class_name: &str) -> Option<()> {
+        let (sign, class_name) = match class_name.strip_prefix('-') {
+            Some(class_name) => (-1.0, class_name),
+            None => (1.0, class_name),
+        };
+        if let Some(value) = class_name.strip_prefix("scale-") {
+            self.scale = Some(sign * parse_amount(value)?);
+        } else if let Some(value) = class_name.strip_prefix("rotate-") {
+            self.rotate = Some(sign * parse_angle(value)?);
+        } else if let Some(value) = class_name.strip_prefix("translate-x-") {
+            self.translate_x = Some(parse_translation(value, sign)?);
+        } else if let Some(value) = class_name.strip_prefix("translate-y-") {
+            self.translate_y = Some(parse_translation(value, sign)?);
+        } else {
+            return None;
+        }
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.scale.is_none()
+            && self.rotate.is_none()
+            && self.translate_x.is_none()
+            && self.translate_y.is_none()
+    }
+
+    fn to_transformation(&self, rem_size: Pixels) -> Transformation {
+        let scale = self.scale.unwrap_or(1.0);
+        let translation = point(
+            self.translate_x.map_or(px(0.0), |x| x.to_pixels(rem_size)),
+            self.translate_y.map_or(px(0.0), |y| y.to_pixels(rem_size)),
+        );
+        Transformation::scale(size(scale, scale))
+            .with_rotation(radians(self.rotate.unwrap_or(0.0)))
+            .with_translation(translation)
+    }
+
+    fn apply_offset<T: Styled>(&self, mut element: T, elem: &str) -> T {
+        if self.scale.is_some() || self.rotate.is_some() {
+            tracing::warn!(
+                "scale and rotate classes only work on <svg>, not on <{}>",
+                elem
+            );
+        }
+        if self.translate_x.is_none() && self.translate_y.is_none() {
+            return element;
+        }
+
+        element = element.relative();
+        if let Some(x) = self.translate_x {
+            element = element.left(x);
+        }
+        if let Some(y) = self.translate_y {
+            element = element.top(y);
+        }
+        element
+    }
+}
+
+// "[1.5]" or Tailwind's percentage, e.g. "150". Scales, brightness and contrast use these
+fn parse_amount(value: &str) -> Option<f32> {
+    match value.strip_prefix('[') {
+        Some(value) => value.strip_suffix(']')?.parse::<f32>().ok(),
+        None => Some(value.parse::<f32>().ok()? / 100.0),
+    }
+}
+
+// "[45deg]", "[0.5turn]", "[1.2rad]" or Tailwind's degrees, e.g. "45". Returns radians
+fn parse_angle(value: &str) -> Option<f32> {
+    let Some(value) = value.strip_prefix('[') else {
+        return Some(value.parse::<f32>().ok()?.to_radians());
+    };
+    let value = value.strip_suffix(']')?;
+
+    if let Some(degrees) = value.strip_suffix("deg") {
+        degrees.parse::<f32>().ok().map(f32::to_radians)
+    } else if let Some(turns) = value.strip_suffix("turn") {
+        turns
+            .parse::<f32>()
+            .ok()
+            .map(|turns| turns * std::f32::consts::TAU)
+    } else if let Some(radians) = value.strip_suffix("rad") {
+        radians.parse::<f32>().ok()
+    } else {
+        None
+    }
+}
+
+// "[12px]", "[1.5rem]", "px" or a step of the spacing scale, e.g. "4"
+fn parse_translation(value: &str, sign: f32) -> Option<AbsoluteLength> {
+    if value == "px" {
+        return Some(px(sign).into());
+    }
+    let Some(value) = value.strip_prefix('[') else {
+        // One step of the spacing scale is 0.25rem
+        return Some(rems(sign * value.parse::<f32>().ok()? / 4.0).into());
+    };
+    let value = value.strip_suffix(']')?;
+
+    if let Some(pixels) = value.strip_suffix("px") {
+        Some(px(sign * pixels.parse::<f32>().ok()?).into())
+    } else if let Some(rem) = value.strip_suffix("rem") {
+        Some(rems(sign * rem.parse::<f32>().ok()?).into())
+    } else {
+        None
+    }
+}
+
+// pointer-events-none makes a div and its children ignore the mouse: no on:click handlers and
+// no hover or active styles, until a child sets pointer-events-auto. GPUI can't let clicks
+// through to what is under the element, and views like inputs handle the mouse themselves.
+// Set while the children of a div are rendered
+pub(crate) struct PointerEvents(bool);
+
+impl Global for PointerEvents {}
+
+impl PointerEvents {
+    // A panic while rendering the children would leave the flag of the div set
+    pub(crate) fn reset(cx: &mut AppContext) {
+        cx.set_global(PointerEvents(true));
+    }
+
+    fn enabled(cx: &AppContext) -> bool {
+        !cx.has_global::<PointerEvents>() || cx.global::<PointerEvents>().0
+    }
+
+    // The last of pointer-events-none and pointer-events-auto wins, None without either
+    fn from_attributes(attributes: &[(String, String)]) -> Option<bool> {
+        let (_, classes) = attributes.iter().find(|(k, _)| k == "class")?;
+        classes
+            .split_whitespace()
+            .filter_map(|class_name| match class_name {
+                "pointer-events-none" => Some(false),
+                "pointer-events-auto" => Some(true),
+                _ => None,
+            })
+            .last()
+    }
+}
+
+// object-cover etc. are only for images, so they can't go through set_attributes. GPUI always
+// centers the image, so object-top, object-[25%_75%] etc. only warn
+const OBJECT_CLASSES: [&str; 14] = [
+    "object-cover",
+    "object-contain",
+    "object-fill",
+    "object-scale-down",
+    "object-none",
+    "object-center",
+    "object-top",
+    "object-bottom",
+    "object-left",
+    "object-right",
+    "object-left-top",
+    "object-left-bottom",
+    "object-right-top",
+    "object-right-bottom",
+];
+
+fn object_fit_from_attributes(attributes: &[(String, String)]) -> Option<ObjectFit> {
+    let (_, classes) = attributes.iter().find(|(k, _)| k == "class")?;
+    let mut object_fit = None;
+    for class_name in classes.split_whitespace() {
+        match class_name {
+            "object-cover" => object_fit = Some(ObjectFit::Cover),
+            "object-contain" => object_fit = Some(ObjectFit::Contain),
+            "object-fill" => object_fit = Some(ObjectFit::Fill),
+            "object-scale-down" => object_fit = Some(ObjectFit::ScaleDown),
+            "object-none" => object_fit = Some(ObjectFit::None),
+            "object-center" => {}
+            _ if OBJECT_CLASSES.contains(&class_name) || class_name.starts_with("object-[") => {
+                tracing::warn!(
+                    "{} not yet supported by GPUI, images are centered",
+                    class_name
+                );
+            }
+            _ => {}
+        }
     }
+    object_fit
+}
+
+// space-x-N and space-y-N put a margin before every child but the first. GPUI has no child
+// selectors, so render_component sets the margins on the children itself
+#[derive(Default)]
+struct ChildSpacing {
+    x: Option<Length>,
+    y: Option<Length>,
+    // space-x-reverse and space-y-reverse, for flex-row-reverse and flex-col-reverse where
+    // the first child is at the end
+    reverse_x: bool,
+    reverse_y: bool,
+}
+
+impl ChildSpacing {
+    fn from_attributes(attributes: &[(String, String)]) -> Option<Self> {
+        let classes = attributes
+            .iter()
+            .find(|(k, _)| k == "class")
+            .map(|(_, v)| v)?;
+
+        let mut spacing = ChildSpacing::default();
+        for class_name in classes.split_whitespace() {
+            spacing.apply_class(class_name);
+        }
+
+        (spacing.x.is_some() || spacing.y.is_some()).then_some(spacing)
+    }
+
+    // None when the class isn't a space class or its value doesn't parse
+    fn apply_class(&mut self, class_name: &str) -> Option<()> {
+        match class_name {
+            "space-x-reverse" => self.reverse_x = true,
+            "space-y-reverse" => self.reverse_y = true,
+            _ => {
+                if let Some(value) = class_name.strip_prefix("space-x-") {
+                    self.x = Some(tailwind_length(value)?);
+                } else if let Some(value) = class_name.strip_prefix("space-y-") {
+                    self.y = Some(tailwind_length(value)?);
+                } else {
+                    return None;
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn apply_margin<T: Styled>(&self, mut element: T) -> T {
+        if let Some(length) = self.x {
+            element = if self.reverse_x {
+                element.mr(length)
+            } else {
+                element.ml(length)
+            };
+        }
+        if let Some(length) = self.y {
+            element = if self.reverse_y {
+                element.mb(length)
+            } else {
+                element.mt(length)
+            };
+        }
+        element
+    }
+}
+
+// Only divs get here, so classes that make sense only for elements with children (gap) are set here
+fn set_stateful_element_attributes<T: StatefulInteractiveElement + Styled>(
+    mut element: T,
+    attributes: &Vec<(String, String)>,
+    theme: &Theme,
+    pointer_events: bool,
+) -> T {
     // Class attribute
     if let Some(class_attr_value) = attributes
         .iter()
@@ -341,159 +2099,1732 @@ fn set_attributes<T: Styled>(mut element: T, attributes: &Vec<(String, String)>)
     {
         // Split the class attribute into individual classes
         let classes = class_attr_value.split_whitespace();
-
-        // Iterate over classes with a loop to allow mutable access to `element`
         for class_name in classes {
-            // Macro magick to convert tailwind classes to gpui. Creates "match class_name { "class-name" => element.class_name() }"
-            element = tailwind_to_gpui!(element, class_name,
-                // Flex
-                [ "flex", "flex-grow", "flex-shrink", "flex-shrink-0" ],
-                // Flex wrap
-                [ "flex-wrap", "flex-wrap-reverse", "flex-nowrap" ],
-                // Align content
-                [ "content-normal", "content-center", "content-start", "content-end", "content-between", "content-around", "content-evenly", "content-stretch" ],
-                // Flex general
-                [ "block", "absolute", "relative", "visible", "invisible", "overflow-hidden", "overflow-x-hidden", "overflow-y-hidden" ],
-                // Align
-                [ "items-start", "items-end", "items-center" ],
-                // Top
-                [ "top-0", "top-1", "top-2", "top-3", "top-4", "top-5", "top-6", "top-8", "top-10", "top-12", "top-16", "top-20", "top-24", "top-32", "top-40", "top-48", "top-56", "top-64", "top-72", "top-80", "top-96", "top-auto", "top-full", "top-1/2", "top-1/3", "top-2/3", "top-1/4", "top-2/4", "top-3/4", "top-1/5", "top-2/5", "top-3/5" ],
-                // Right
-                [ "right-0", "right-1", "right-2", "right-3", "right-4", "right-5", "right-6", "right-8", "right-10", "right-12", "right-16", "right-20", "right-24", "right-32", "right-40", "right-48", "right-56", "right-64", "right-72", "right-80", "right-96", "right-auto", "right-full", "right-1/2", "right-1/3", "right-2/3", "right-1/4", "right-2/4", "right-3/4", "right-1/5", "right-2/5", "right-3/5" ],
-                // Bottom
-                [ "bottom-0", "bottom-1", "bottom-2", "bottom-3", "bottom-4", "bottom-5", "bottom-6", "bottom-8", "bottom-10", "bottom-12", "bottom-16", "bottom-20", "bottom-24", "bottom-32", "bottom-40", "bottom-48", "bottom-56", "bottom-64", "bottom-72", "bottom-80", "bottom-96", "bottom-auto", "bottom-full", "bottom-1/2", "bottom-1/3", "bottom-2/3", "bottom-1/4", "bottom-2/4", "bottom-3/4", "bottom-1/5", "bottom-2/5", "bottom-3/5" ],
-                // Left
-                [ "left-0", "left-1", "left-2", "left-3", "left-4", "left-5", "left-6", "left-8", "left-10", "left-12", "left-16", "left-20", "left-24", "left-32", "left-40", "left-48", "left-56", "left-64", "left-72", "left-80", "left-96", "left-auto", "left-full", "left-1/2", "left-1/3", "left-2/3", "left-1/4", "left-2/4", "left-3/4", "left-1/5", "left-2/5", "left-3/5" ],
-                // Cursor
-                [ "cursor-default", "cursor-pointer", "cursor-text", "cursor-move", "cursor-not-allowed", "cursor-context-menu", "cursor-crosshair", "cursor-vertical-text", "cursor-alias", "cursor-copy", "cursor-no-drop", "cursor-grab", "cursor-grabbing", "cursor-col-resize", "cursor-row-resize", "cursor-n-resize", "cursor-e-resize", "cursor-s-resize", "cursor-w-resize" ],
-                // Justify
-                [ "justify-center", "justify-between", "justify-around", "justify-start", "justify-end" ],
-                // Flex
-                [ "flex-col", "flex-row", "flex-col_reverse", "flex-row_reverse", "flex-1", "flex-auto", "flex-initial", "flex-none" ],
-                // Shadow
-                [ "shadow-sm", "shadow-md", "shadow-lg", "shadow-xl", "shadow-2xl" ],
-                // Height
-                [ "h-0", "h-1", "h-2", "h-3", "h-4", "h-5", "h-6", "h-8", "h-10", "h-12", "h-16", "h-20", "h-24", "h-32", "h-40", "h-48", "h-56", "h-64", "h-72", "h-80", "h-96", "h-auto", "h-full", "h-1/2", "h-1/3", "h-2/3", "h-1/4", "h-2/4", "h-3/4", "h-1/5", "h-2/5", "h-3/5", "h-4/5", "h-1/6", "h-5/6", "h-1/12" ],
-                // Width
-                [ "w-0", "w-1", "w-2", "w-3", "w-4", "w-5", "w-6", "w-8", "w-10", "w-12", "w-16", "w-20", "w-24", "w-32", "w-40", "w-48", "w-56", "w-64", "w-72", "w-80", "w-96", "w-auto", "w-full", "w-1/2", "w-1/3", "w-2/3", "w-1/4", "w-2/4", "w-3/4", "w-1/5", "w-2/5", "w-3/5", "w-4/5", "w-1/6", "w-5/6", "w-1/12" ],
-                // Max and min height and width
-                [ "min-h-0", "min-h-full", "min-w-0", "min-w-full", "max-h-0", "max-h-full", "max-w-0", "max-w-full" ],
-                // Padding
-                [ "p-0", "p-1", "p-2", "p-3", "p-4", "p-5", "p-6", "p-8", "p-10", "p-12", "p-16", "p-20", "p-24", "p-32", "p-40", "p-48", "p-56", "p-64", "p-72", "p-80", "p-96", "p-full", "p-1/2", "p-1/3", "p-2/3", "p-1/4", "p-2/4", "p-3/4", "p-1/5", "p-2/5", "p-3/5", "p-4/5", "p-1/6", "p-5/6", "p-1/12" ],
-                [ "px-0", "px-1", "px-2", "px-3", "px-4", "px-5", "px-6", "px-8", "px-10", "px-12", "px-16", "px-20", "px-24", "px-32", "px-40", "px-48", "px-56", "px-64", "px-72", "px-80", "px-96", "px-full", "px-1/2", "px-1/3", "px-2/3", "px-1/4", "px-2/4", "px-3/4", "px-1/5", "px-2/5", "px-3/5", "px-4/5", "px-1/6", "px-5/6", "px-1/12" ],
-                [ "py-0", "py-1", "py-2", "py-3", "py-4", "py-5", "py-6", "py-8", "py-10", "py-12", "py-16", "py-20", "py-24", "py-32", "py-40", "py-48", "py-56", "py-64", "py-72", "py-80", "py-96", "py-full", "py-1/2", "py-1/3", "py-2/3", "py-1/4", "py-2/4", "py-3/4", "py-1/5", "py-2/5", "py-3/5", "py-4/5", "py-1/6", "py-5/6", "py-1/12" ],
-                [ "pt-0", "pt-1", "pt-2", "pt-3", "pt-4", "pt-5", "pt-6", "pt-8", "pt-10", "pt-12", "pt-16", "pt-20", "pt-24", "pt-32", "pt-40", "pt-48", "pt-56", "pt-64", "pt-72", "pt-80", "pt-96", "pt-full", "pt-1/2", "pt-1/3", "pt-2/3", "pt-1/4", "pt-2/4", "pt-3/4", "pt-1/5", "pt-2/5", "pt-3/5", "pt-4/5", "pt-1/6", "pt-5/6", "pt-1/12" ],
-                [ "pr-0", "pr-1", "pr-2", "pr-3", "pr-4", "pr-5", "pr-6", "pr-8", "pr-10", "pr-12", "pr-16", "pr-20", "pr-24", "pr-32", "pr-40", "pr-48", "pr-56", "pr-64", "pr-72", "pr-80", "pr-96", "pr-full", "pr-1/2", "pr-1/3", "pr-2/3", "pr-1/4", "pr-2/4", "pr-3/4", "pr-1/5", "pr-2/5", "pr-3/5", "pr-4/5", "pr-1/6", "pr-5/6", "pr-1/12" ],
-                [ "pb-0", "pb-1", "pb-2", "pb-3", "pb-4", "pb-5", "pb-6", "pb-8", "pb-10", "pb-12", "pb-16", "pb-20", "pb-24", "pb-32", "pb-40", "pb-48", "pb-56", "pb-64", "pb-72", "pb-80", "pb-96", "pb-full", "pb-1/2", "pb-1/3", "pb-2/3", "pb-1/4", "pb-2/4", "pb-3/4", "pb-1/5", "pb-2/5", "pb-3/5", "pb-4/5", "pb-1/6", "pb-5/6", "pb-1/12" ],
-                [ "pl-0", "pl-1", "pl-2", "pl-3", "pl-4", "pl-5", "pl-6", "pl-8", "pl-10", "pl-12", "pl-16", "pl-20", "pl-24", "pl-32", "pl-40", "pl-48", "pl-56", "pl-64", "pl-72", "pl-80", "pl-96", "pl-full", "pl-1/2", "pl-1/3", "pl-2/3", "pl-1/4", "pl-2/4", "pl-3/4", "pl-1/5", "pl-2/5", "pl-3/5", "pl-4/5", "pl-1/6", "pl-5/6", "pl-1/12" ],
-                // Margin
-                [ "m-0", "m-1", "m-2", "m-3", "m-4", "m-5", "m-6", "m-8", "m-10", "m-12", "m-16", "m-20", "m-24", "m-32", "m-40", "m-48", "m-56", "m-64", "m-72", "m-80", "m-96", "m-auto", "m-full", "m-1/2", "m-1/3", "m-2/3", "m-1/4", "m-2/4", "m-3/4", "m-1/5", "m-2/5", "m-3/5", "m-4/5", "m-1/6", "m-5/6", "m-1/12" ],
-                [ "mx-0", "mx-1", "mx-2", "mx-3", "mx-4", "mx-5", "mx-6", "mx-8", "mx-10", "mx-12", "mx-16", "mx-20", "mx-24", "mx-32", "mx-40", "mx-48", "mx-56", "mx-64", "mx-72", "mx-80", "mx-96", "mx-auto", "mx-full", "mx-1/2", "mx-1/3", "mx-2/3", "mx-1/4", "mx-2/4", "mx-3/4", "mx-1/5", "mx-2/5", "mx-3/5", "mx-4/5", "mx-1/6", "mx-5/6", "mx-1/12" ],
-                [ "my-0", "my-1", "my-2", "my-3", "my-4", "my-5", "my-6", "my-8", "my-10", "my-12", "my-16", "my-20", "my-24", "my-32", "my-40", "my-48", "my-56", "my-64", "my-72", "my-80", "my-96", "my-auto", "my-full", "my-1/2", "my-1/3", "my-2/3", "my-1/4", "my-2/4", "my-3/4", "my-1/5", "my-2/5", "my-3/5", "my-4/5", "my-1/6", "my-5/6", "my-1/12" ],
-                [ "mt-0", "mt-1", "mt-2", "mt-3", "mt-4", "mt-5", "mt-6", "mt-8", "mt-10", "mt-12", "mt-16", "mt-20", "mt-24", "mt-32", "mt-40", "mt-48", "mt-56", "mt-64", "mt-72", "mt-80", "mt-96", "mt-auto", "mt-full", "mt-1/2", "mt-1/3", "mt-2/3", "mt-1/4", "mt-2/4", "mt-3/4", "mt-1/5", "mt-2/5", "mt-3/5", "mt-4/5", "mt-1/6", "mt-5/6", "mt-1/12" ],
-                [ "mr-0", "mr-1", "mr-2", "mr-3", "mr-4", "mr-5", "mr-6", "mr-8", "mr-10", "mr-12", "mr-16", "mr-20", "mr-24", "mr-32", "mr-40", "mr-48", "mr-56", "mr-64", "mr-72", "mr-80", "mr-96", "mr-auto", "mr-full", "mr-1/2", "mr-1/3", "mr-2/3", "mr-1/4", "mr-2/4", "mr-3/4", "mr-1/5", "mr-2/5", "mr-3/5", "mr-4/5", "mr-1/6", "mr-5/6", "mr-1/12" ],
-                [ "mb-0", "mb-1", "mb-2", "mb-3", "mb-4", "mb-5", "mb-6", "mb-8", "mb-10", "mb-12", "mb-16", "mb-20", "mb-24", "mb-32", "mb-40", "mb-48", "mb-56", "mb-64", "mb-72", "mb-80", "mb-96", "mb-auto", "mb-full", "mb-1/2", "mb-1/3", "mb-2/3", "mb-1/4", "mb-2/4", "mb-3/4", "mb-1/5", "mb-2/5", "mb-3/5", "mb-4/5", "mb-1/6", "mb-5/6", "mb-1/12" ],
-                [ "ml-0", "ml-1", "ml-2", "ml-3", "ml-4", "ml-5", "ml-6", "ml-8", "ml-10", "ml-12", "ml-16", "ml-20", "ml-24", "ml-32", "ml-40", "ml-48", "ml-56", "ml-64", "ml-72", "ml-80", "ml-96", "ml-auto", "ml-full", "ml-1/2", "ml-1/3", "ml-2/3", "ml-1/4", "ml-2/4", "ml-3/4", "ml-1/5", "ml-2/5", "ml-3/5", "ml-4/5", "ml-1/6", "ml-5/6", "ml-1/12" ],
-                // Border
-                [ "border", "border-0", "border-1", "border-2", "border-3", "border-4", "border-5", "border-6", "border-8", "border-10", "border-12", "border-16", "border-20", "border-24", "border-32" ],
-                // Border width
-                [ "border-t", "border-t-0", "border-t-1", "border-t-2", "border-t-3", "border-t-4", "border-t-5", "border-t-6", "border-t-8", "border-t-10", "border-t-12", "border-t-16", "border-t-20", "border-t-24", "border-t-32" ],
-                [ "border-r", "border-r-0", "border-r-1", "border-r-2", "border-r-3", "border-r-4", "border-r-5", "border-r-6", "border-r-8", "border-r-10", "border-r-12", "border-r-16", "border-r-20", "border-r-24", "border-r-32" ],
-                [ "border-b", "border-b-0", "border-b-1", "border-b-2", "border-b-3", "border-b-4", "border-b-5", "border-b-6", "border-b-8", "border-b-10", "border-b-12", "border-b-16", "border-b-20", "border-b-24", "border-b-32" ],
-                [ "border-l", "border-l-0", "border-l-1", "border-l-2", "border-l-3", "border-l-4", "border-l-5", "border-l-6", "border-l-8", "border-l-10", "border-l-12", "border-l-16", "border-l-20", "border-l-24", "border-l-32" ],
-                // Border radius
-                [ "rounded-none", "rounded-sm", "rounded-md", "rounded-lg", "rounded-xl", "rounded-2xl", "rounded-3xl", "rounded-full" ],
-                [ "rounded-t-none", "rounded-t-sm", "rounded-t-md", "rounded-t-lg", "rounded-t-xl", "rounded-t-2xl", "rounded-t-3xl", "rounded-t-full" ],
-                [ "rounded-r-none", "rounded-r-sm", "rounded-r-md", "rounded-r-lg", "rounded-r-xl", "rounded-r-2xl", "rounded-r-3xl", "rounded-r-full" ],
-                [ "rounded-b-none", "rounded-b-sm", "rounded-b-md", "rounded-b-lg", "rounded-b-xl", "rounded-b-2xl", "rounded-b-3xl", "rounded-b-full" ],
-                [ "rounded-l-none", "rounded-l-sm", "rounded-l-md", "rounded-l-lg", "rounded-l-xl", "rounded-l-2xl", "rounded-l-3xl", "rounded-l-full" ],
-                [ "rounded-tl-none", "rounded-tl-sm", "rounded-tl-md", "rounded-tl-lg", "rounded-tl-xl", "rounded-tl-2xl", "rounded-tl-3xl", "rounded-tl-full" ],
-                [ "rounded-tr-none", "rounded-tr-sm", "rounded-tr-md", "rounded-tr-lg", "rounded-tr-xl", "rounded-tr-2xl", "rounded-tr-3xl", "rounded-tr-full" ],
-                [ "rounded-br-none", "rounded-br-sm", "rounded-br-md", "rounded-br-lg", "rounded-br-xl", "rounded-br-2xl", "rounded-br-3xl", "rounded-br-full" ],
-                [ "rounded-bl-none", "rounded-bl-sm", "rounded-bl-md", "rounded-bl-lg", "rounded-bl-xl", "rounded-bl-2xl", "rounded-bl-3xl", "rounded-bl-full" ],
-                // Font
-                [ "font-thin", "font-extralight", "font-light", "font-normal", "font-medium", "font-semibold", "font-bold", "font-extrabold", "font-black" ],
-                // Text
-                [ "text-xs", "text-sm", "text-base", "text-lg", "text-xl", "text-2xl", "text-3xl" ],
-                // Sizes
-                [ "size-0", "size-0.5", "size-1", "size-1.5", "size-2", "size-2.5", "size-3", "size-3.5", "size-4", "size-5", "size-6", "size-8", "size-10", "size-12", "size-16", "size-20", "size-24", "size-32", "size-40", "size-48", "size-56", "size-64", "size-72", "size-80", "size-96", "size-1/2", "size-1/3", "size-2/3", "size-1/4", "size-2/4", "size-3/4", "size-1/5", "size-2/5", "size-3/5", "size-4/5", "size-1/6", "size-5/6", "size-1/12", "size-full", "size-auto" ],
-
-                // Dynamic sizes and colors
-                _ => {
-                    // Handle dynamic background colors
-                    if class_name.starts_with("bg-[#") {
-                        let hex = &class_name["bg-[#".len()..class_name.len() - 1];
-                        let color = hex_to_rgba(hex);
-                        element.bg(color)
-                    }
-                    // Handle dynamic text colors
-                    else if class_name.starts_with("text-color-[#") {
-                        let hex = &class_name["text-color-[#".len()..class_name.len() - 1];
-                        let color = hex_to_rgba(hex);
-                        element.text_color(color)
-                    }
-                    // Handle dynamic border colors
-                    else if class_name.starts_with("border-[#") {
-                        let hex = &class_name["border-[#".len()..class_name.len() - 1];
-                        let color = hex_to_rgba(hex);
-                        element.border_color(color)
-                    }
-                    // Rounded with any px or rem value
-                    else if let Some(suffix) = class_name.strip_prefix("rounded-") {
-                        let absolute_length = extract_length_from_class_name(suffix);
-
-                        match suffix.split('-').next() {
-                            Some("t") => element.rounded_t(absolute_length),
-                            Some("r") => element.rounded_r(absolute_length),
-                            Some("b") => element.rounded_b(absolute_length),
-                            Some("l") => element.rounded_l(absolute_length),
-                            Some("tl") => element.rounded_tl(absolute_length),
-                            Some("tr") => element.rounded_tr(absolute_length),
-                            Some("br") => element.rounded_br(absolute_length),
-                            Some("bl") => element.rounded_bl(absolute_length),
-                            _ => element.rounded(absolute_length), // Default to applying rounding to all corners
-                        }
-                    }
-                    // Border with any px or rem value
-                    else if let Some(suffix) = class_name.strip_prefix("border-") {
-                        let absolute_length = extract_length_from_class_name(suffix);
-                        match suffix.split('-').next() {
-                            Some("t") => element.border_t_width(absolute_length),
-                            Some("r") => element.border_r_width(absolute_length),
-                            Some("b") => element.border_b_width(absolute_length),
-                            Some("l") => element.border_l_width(absolute_length),
-                            _ => element.rounded(absolute_length), // Default to applying rounding to all corners
-                        }
-                    }
-                    else {
-                        element
-                    }
+            // Dark mode classes are set by set_attributes, they work on any element
+            if class_name.starts_with("dark:") {
+                continue;
+            }
+            // State modifiers like hover:bg-[#ccc] apply the inner class to that state's style.
+            // With pointer-events-none there is no hover or active state
+            if let Some((modifier, inner_class)) = class_name.split_once(':') {
+                if pointer_events {
+                    element = apply_modified_class(element, modifier, inner_class, theme);
                 }
-            );
+                continue;
+            }
+
+            element =
+                try_apply_stateful_class(element, class_name).unwrap_or_else(|element| element);
         }
     }
-
     element
 }
 
-// Extracts the numeric value and unit from the class name, returning an AbsoluteLength
-fn extract_length_from_class_name(class_name: &str) -> AbsoluteLength {
-    let numeric_part: String = class_name
-        .chars()
-        .skip_while(|c| !c.is_digit(10) && *c != '.')
-        .take_while(|c| c.is_digit(10) || *c == '.')
-        .collect();
+// Classes that only divs support, see try_apply_class
+pub(crate) fn try_apply_stateful_class<T: StatefulInteractiveElement + Styled>(
+    element: T,
+    class_name: &str,
+) -> Result<T, T> {
+    Ok(tailwind_to_gpui!(element, class_name,
+        [ "overflow-scroll", "overflow-x-scroll", "overflow-y-scroll" ],
+        // Gap
+        [ "gap-0", "gap-0.5", "gap-1", "gap-1.5", "gap-2", "gap-2.5", "gap-3", "gap-3.5", "gap-4", "gap-5", "gap-6", "gap-8", "gap-10", "gap-12", "gap-16", "gap-20", "gap-24", "gap-32", "gap-40", "gap-48", "gap-56", "gap-64", "gap-72", "gap-80", "gap-96", "gap-px" ],
+        [ "gap-x-0", "gap-x-0.5", "gap-x-1", "gap-x-1.5", "gap-x-2", "gap-x-2.5", "gap-x-3", "gap-x-3.5", "gap-x-4", "gap-x-5", "gap-x-6", "gap-x-8", "gap-x-10", "gap-x-12", "gap-x-16", "gap-x-20", "gap-x-24", "gap-x-32", "gap-x-40", "gap-x-48", "gap-x-56", "gap-x-64", "gap-x-72", "gap-x-80", "gap-x-96", "gap-x-px" ],
+        [ "gap-y-0", "gap-y-0.5", "gap-y-1", "gap-y-1.5", "gap-y-2", "gap-y-2.5", "gap-y-3", "gap-y-3.5", "gap-y-4", "gap-y-5", "gap-y-6", "gap-y-8", "gap-y-10", "gap-y-12", "gap-y-16", "gap-y-20", "gap-y-24", "gap-y-32", "gap-y-40", "gap-y-48", "gap-y-56", "gap-y-64", "gap-y-72", "gap-y-80", "gap-y-96", "gap-y-px" ],
+        _ => {
+            // Gap with any px or rem value, e.g. gap-[12px] or gap-x-[1.5rem]
+            if let Some(suffix) = class_name.strip_prefix("gap-x-[") {
+                element.gap_x(definite_length(extract_length_from_class_name(suffix)))
+            } else if let Some(suffix) = class_name.strip_prefix("gap-y-[") {
+                element.gap_y(definite_length(extract_length_from_class_name(suffix)))
+            } else if let Some(suffix) = class_name.strip_prefix("gap-[") {
+                element.gap(definite_length(extract_length_from_class_name(suffix)))
+            }
+            // Child spacing is set on the children by render_component, see ChildSpacing
+            else if ChildSpacing::default().apply_class(class_name).is_some() {
+                element
+            }
+            // Marks the parent for group-hover: classes of its children
+            else if class_name == "group" {
+                element.group("group")
+            } else {
+                return Err(element);
+            }
+        }
+    ))
+}
 
-    let unit_part: String = class_name
-        .chars()
-        .skip_while(|c| c.is_digit(10) || *c == '.')
-        .collect();
+// Modifiers apply_modified_class understands
+pub(crate) const STATE_MODIFIERS: [&str; 4] = ["hover", "active", "focus", "group-hover"];
 
-    let rounded_value = numeric_part.parse::<f32>().unwrap_or_default();
+// Applies a class with a state modifier prefix, e.g. hover:bg-[#ccc] or active:opacity-75
+fn apply_modified_class<T: StatefulInteractiveElement + Styled>(
+    element: T,
+    modifier: &str,
+    class_name: &str,
+    theme: &Theme,
+) -> T {
+    let apply = |style: StyleRefinement| apply_themed_class(style, class_name, theme);
+    match modifier {
+        "hover" => element.hover(apply),
+        "active" => element.active(apply),
+        "focus" => element.focus(apply),
+        "group-hover" => element.group_hover("group", apply),
+        _ => {
+            tracing::warn!(
+                "Unsupported state modifier \"{}:\" in \"{}:{}\"",
+                modifier,
+                modifier,
+                class_name
+            );
+            element
+        }
+    }
+}
+
+// Only divs support state modifiers for now, other elements just report them
+fn warn_unsupported_modifiers(component: &Component) {
+    if let Some(class_attr_value) = component
+        .attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .map(|(_, v)| v)
+    {
+        for class_name in class_attr_value
+            .split_whitespace()
+            .filter(|class_name| class_name.contains(':') && !class_name.starts_with("dark:"))
+        {
+            tracing::warn!(
+                "State modifier class \"{}\" is not supported on <{}>",
+                class_name,
+                component.elem
+            );
+        }
+    }
+}
+
+fn set_attributes<T: Styled>(
+    mut element: T,
+    attributes: &Vec<(String, String)>,
+    theme: &Theme,
+) -> T {
+    // Custom attributes that has some default styles
+    if let Some(type_value) = attributes
+        .iter()
+        .find(|(k, _)| k == "type")
+        .map(|(_, v)| v.as_str())
+    {
+        match type_value {
+            "maintable" => {
+                element = element
+                    .flex()
+                    .flex_col()
+                    .p_1()
+                    .size_full()
+                    .border_1()
+                    .border_color(rgb(0x000000));
+            }
+            "subtable" => {
+                element = element
+                    .flex()
+                    .p_1()
+                    .size_full()
+                    .border_1()
+                    .border_color(rgb(0x0000ff));
+            }
+            _ => {}
+        }
+    }
+    // Font attribute
+    if let Some(font_attr_value) = attributes.iter().find(|(k, _)| k == "font").map(|(_, v)| v) {
+        let font: SharedString = SharedString::from(font_attr_value.clone());
+        element = element.font(font);
+    }
+    // Class attribute
+    if let Some(class_attr_value) = attributes
+        .iter()
+        .find(|(k, _)| k == "class")
+        .map(|(_, v)| v)
+    {
+        // Split the class attribute into individual classes. Shadow colors go last, they
+        // recolor the shadow that shadow-sm etc. set
+        let (shadow_colors, classes): (Vec<&str>, Vec<&str>) = class_attr_value
+            .split_whitespace()
+            .partition(|class_name| class_name.starts_with("shadow-color-"));
+
+        // Iterate over classes with a loop to allow mutable access to `element`
+        for class_name in classes.into_iter().chain(shadow_colors) {
+            // Classes with a state modifier (hover:bg-[#ccc]) are set by set_stateful_element_attributes
+            if class_name.contains(':') {
+                continue;
+            }
+            element = apply_themed_class(element, class_name, theme);
+        }
+
+        // dark:bg-surface etc. go after the other classes, so they override them in dark mode
+        if theme.is_dark() {
+            for class_name in class_attr_value
+                .split_whitespace()
+                .filter_map(|class_name| class_name.strip_prefix("dark:"))
+            {
+                element = apply_themed_class(element, class_name, theme);
+            }
+        }
+    }
+
+    element
+}
+
+// Token of a theme color class like bg-primary, text-error or border-surface
+fn theme_class_token(class_name: &str) -> Option<&str> {
+    let (property, token) = class_name.split_once('-')?;
+    (matches!(property, "bg" | "text" | "border") && THEME_TOKENS.contains(&token)).then_some(token)
+}
+
+// Gives the element back in Err when the class is not a theme color
+fn apply_theme_class<T: Styled>(element: T, class_name: &str, theme: &Theme) -> Result<T, T> {
+    let Some(token) = theme_class_token(class_name) else {
+        return Err(element);
+    };
+    // Invalid colors in the theme are logged by token()
+    let Some(color) = theme.token(token) else {
+        return Ok(element);
+    };
+    Ok(
+        match class_name.split_once('-').map(|(property, _)| property) {
+            Some("bg") => element.bg(color),
+            Some("text") => element.text_color(color),
+            _ => element.border_color(color),
+        },
+    )
+}
+
+// Like apply_class, but also knows the theme colors
+fn apply_themed_class<T: Styled>(element: T, class_name: &str, theme: &Theme) -> T {
+    apply_theme_class(element, class_name, theme)
+        .unwrap_or_else(|element| apply_class(element, class_name))
+}
+
+// Applies a single tailwind class. Works also on a StyleRefinement, which state modifiers use
+fn apply_class<T: Styled>(element: T, class_name: &str) -> T {
+    try_apply_class(element, class_name).unwrap_or_else(|element| element)
+}
+
+// Families of font-sans, font-serif and font-mono. Roboto is embedded in the app, the others
+// come from the system or the user's fonts directory
+const FONT_SANS: &str = "Roboto";
+const FONT_SERIF: &str = "Noto Serif";
+const FONT_MONO: &str = "JetBrains Mono";
+
+// Line height (rem) of the line-clamp-N classes
+const LINE_CLAMP_LINE_HEIGHT: f32 = 1.5;
+
+// Gives the element back in Err when the class is not recognized, so the validator can tell
+// typos from classes that are supported
+pub(crate) fn try_apply_class<T: Styled>(element: T, class_name: &str) -> Result<T, T> {
+    // Macro magick to convert tailwind classes to gpui. Creates "match class_name { "class-name" => element.class_name() }"
+    // Classes whose method is named differently are given as ("class-name", method) pairs, and
+    // classes that need arguments or more than one method as calls, e.g. ("text-4xl", text_size(rems(2.25)))
+    Ok(tailwind_to_gpui!(element, class_name,
+        // Flex
+        [ "flex", "flex-grow", "flex-shrink", "flex-shrink-0", ("grow", flex_grow), ("shrink", flex_shrink), ("shrink-0", flex_shrink_0) ],
+        // Grid is a wrapping flex row, render_component sizes the children (see grid_columns)
+        [ ("grid", flex().flex_row().flex_wrap()) ],
+        // Flex wrap
+        [ "flex-wrap", "flex-wrap-reverse", "flex-nowrap" ],
+        // Align content
+        [ "content-normal", "content-center", "content-start", "content-end", "content-between", "content-around", "content-evenly", "content-stretch" ],
+        // Flex general
+        [ "block", "absolute", "relative", "visible", "invisible", "overflow-hidden", "overflow-x-hidden", "overflow-y-hidden" ],
+        // Whitespace
+        [ "whitespace-normal", "whitespace-nowrap" ],
+        // Tailwind truncate is overflow-hidden + whitespace-nowrap + text-ellipsis. GPUI can't draw the ellipsis, so the text is just clipped
+        [ ("truncate", overflow_hidden().whitespace_nowrap()) ],
+        // Align
+        [ "items-start", "items-end", "items-center" ],
+        // Top
+        [ "top-0", "top-1", "top-2", "top-3", "top-4", "top-5", "top-6", "top-8", "top-10", "top-12", "top-16", "top-20", "top-24", "top-32", "top-40", "top-48", "top-56", "top-64", "top-72", "top-80", "top-96", "top-auto", "top-full", "top-1/2", "top-1/3", "top-2/3", "top-1/4", "top-2/4", "top-3/4", "top-1/5", "top-2/5", "top-3/5" ],
+        // Right
+        [ "right-0", "right-1", "right-2", "right-3", "right-4", "right-5", "right-6", "right-8", "right-10", "right-12", "right-16", "right-20", "right-24", "right-32", "right-40", "right-48", "right-56", "right-64", "right-72", "right-80", "right-96", "right-auto", "right-full", "right-1/2", "right-1/3", "right-2/3", "right-1/4", "right-2/4", "right-3/4", "right-1/5", "right-2/5", "right-3/5" ],
+        // Bottom
+        [ "bottom-0", "bottom-1", "bottom-2", "bottom-3", "bottom-4", "bottom-5", "bottom-6", "bottom-8", "bottom-10", "bottom-12", "bottom-16", "bottom-20", "bottom-24", "bottom-32", "bottom-40", "bottom-48", "bottom-56", "bottom-64", "bottom-72", "bottom-80", "bottom-96", "bottom-auto", "bottom-full", "bottom-1/2", "bottom-1/3", "bottom-2/3", "bottom-1/4", "bottom-2/4", "bottom-3/4", "bottom-1/5", "bottom-2/5", "bottom-3/5" ],
+        // Left
+        [ "left-0", "left-1", "left-2", "left-3", "left-4", "left-5", "left-6", "left-8", "left-10", "left-12", "left-16", "left-20", "left-24", "left-32", "left-40", "left-48", "left-56", "left-64", "left-72", "left-80", "left-96", "left-auto", "left-full", "left-1/2", "left-1/3", "left-2/3", "left-1/4", "left-2/4", "left-3/4", "left-1/5", "left-2/5", "left-3/5" ],
+        // Cursor
+        [ "cursor-default", "cursor-pointer", "cursor-text", "cursor-move", "cursor-not-allowed", "cursor-context-menu", "cursor-crosshair", "cursor-vertical-text", "cursor-alias", "cursor-copy", "cursor-no-drop", "cursor-grab", "cursor-grabbing", "cursor-col-resize", "cursor-row-resize", "cursor-n-resize", "cursor-e-resize", "cursor-s-resize", "cursor-w-resize" ],
+        // Justify
+        [ "justify-center", "justify-between", "justify-around", "justify-start", "justify-end" ],
+        // Flex
+        [ "flex-col", "flex-row", ("flex-col-reverse", flex_col_reverse), ("flex-row-reverse", flex_row_reverse), "flex-1", "flex-auto", "flex-initial", "flex-none" ],
+        // Shadow
+        [ "shadow-sm", "shadow-md", "shadow-lg", "shadow-xl", "shadow-2xl" ],
+        // Height
+        [ "h-0", "h-1", "h-2", "h-3", "h-4", "h-5", "h-6", "h-8", "h-10", "h-12", "h-16", "h-20", "h-24", "h-32", "h-40", "h-48", "h-56", "h-64", "h-72", "h-80", "h-96", "h-auto", "h-full", "h-1/2", "h-1/3", "h-2/3", "h-1/4", "h-2/4", "h-3/4", "h-1/5", "h-2/5", "h-3/5", "h-4/5", "h-1/6", "h-5/6", "h-1/12" ],
+        // Width
+        [ "w-0", "w-1", "w-2", "w-3", "w-4", "w-5", "w-6", "w-8", "w-10", "w-12", "w-16", "w-20", "w-24", "w-32", "w-40", "w-48", "w-56", "w-64", "w-72", "w-80", "w-96", "w-auto", "w-full", "w-1/2", "w-1/3", "w-2/3", "w-1/4", "w-2/4", "w-3/4", "w-1/5", "w-2/5", "w-3/5", "w-4/5", "w-1/6", "w-5/6", "w-1/12" ],
+        // Max and min height and width
+        [ "min-h-0", "min-h-full", "min-w-0", "min-w-full", "max-h-0", "max-h-full", "max-w-0", "max-w-full" ],
+        // Padding
+        [ "p-0", "p-1", "p-2", "p-3", "p-4", "p-5", "p-6", "p-8", "p-10", "p-12", "p-16", "p-20", "p-24", "p-32", "p-40", "p-48", "p-56", "p-64", "p-72", "p-80", "p-96", "p-full", "p-1/2", "p-1/3", "p-2/3", "p-1/4", "p-2/4", "p-3/4", "p-1/5", "p-2/5", "p-3/5", "p-4/5", "p-1/6", "p-5/6", "p-1/12" ],
+        [ "px-0", "px-1", "px-2", "px-3", "px-4", "px-5", "px-6", "px-8", "px-10", "px-12", "px-16", "px-20", "px-24", "px-32", "px-40", "px-48", "px-56", "px-64", "px-72", "px-80", "px-96", "px-full", "px-1/2", "px-1/3", "px-2/3", "px-1/4", "px-2/4", "px-3/4", "px-1/5", "px-2/5", "px-3/5", "px-4/5", "px-1/6", "px-5/6", "px-1/12" ],
+        [ "py-0", "py-1", "py-2", "py-3", "py-4", "py-5", "py-6", "py-8", "py-10", "py-12", "py-16", "py-20", "py-24", "py-32", "py-40", "py-48", "py-56", "py-64", "py-72", "py-80", "py-96", "py-full", "py-1/2", "py-1/3", "py-2/3", "py-1/4", "py-2/4", "py-3/4", "py-1/5", "py-2/5", "py-3/5", "py-4/5", "py-1/6", "py-5/6", "py-1/12" ],
+        [ "pt-0", "pt-1", "pt-2", "pt-3", "pt-4", "pt-5", "pt-6", "pt-8", "pt-10", "pt-12", "pt-16", "pt-20", "pt-24", "pt-32", "pt-40", "pt-48", "pt-56", "pt-64", "pt-72", "pt-80", "pt-96", "pt-full", "pt-1/2", "pt-1/3", "pt-2/3", "pt-1/4", "pt-2/4", "pt-3/4", "pt-1/5", "pt-2/5", "pt-3/5", "pt-4/5", "pt-1/6", "pt-5/6", "pt-1/12" ],
+        [ "pr-0", "pr-1", "pr-2", "pr-3", "pr-4", "pr-5", "pr-6", "pr-8", "pr-10", "pr-12", "pr-16", "pr-20", "pr-24", "pr-32", "pr-40", "pr-48", "pr-56", "pr-64", "pr-72", "pr-80", "pr-96", "pr-full", "pr-1/2", "pr-1/3", "pr-2/3", "pr-1/4", "pr-2/4", "pr-3/4", "pr-1/5", "pr-2/5", "pr-3/5", "pr-4/5", "pr-1/6", "pr-5/6", "pr-1/12" ],
+        [ "pb-0", "pb-1", "pb-2", "pb-3", "pb-4", "pb-5", "pb-6", "pb-8", "pb-10", "pb-12", "pb-16", "pb-20", "pb-24", "pb-32", "pb-40", "pb-48", "pb-56", "pb-64", "pb-72", "pb-80", "pb-96", "pb-full", "pb-1/2", "pb-1/3", "pb-2/3", "pb-1/4", "pb-2/4", "pb-3/4", "pb-1/5", "pb-2/5", "pb-3/5", "pb-4/5", "pb-1/6", "pb-5/6", "pb-1/12" ],
+        [ "pl-0", "pl-1", "pl-2", "pl-3", "pl-4", "pl-5", "pl-6", "pl-8", "pl-10", "pl-12", "pl-16", "pl-20", "pl-24", "pl-32", "pl-40", "pl-48", "pl-56", "pl-64", "pl-72", "pl-80", "pl-96", "pl-full", "pl-1/2", "pl-1/3", "pl-2/3", "pl-1/4", "pl-2/4", "pl-3/4", "pl-1/5", "pl-2/5", "pl-3/5", "pl-4/5", "pl-1/6", "pl-5/6", "pl-1/12" ],
+        // Margin
+        [ "m-0", "m-1", "m-2", "m-3", "m-4", "m-5", "m-6", "m-8", "m-10", "m-12", "m-16", "m-20", "m-24", "m-32", "m-40", "m-48", "m-56", "m-64", "m-72", "m-80", "m-96", "m-auto", "m-full", "m-1/2", "m-1/3", "m-2/3", "m-1/4", "m-2/4", "m-3/4", "m-1/5", "m-2/5", "m-3/5", "m-4/5", "m-1/6", "m-5/6", "m-1/12" ],
+        [ "mx-0", "mx-1", "mx-2", "mx-3", "mx-4", "mx-5", "mx-6", "mx-8", "mx-10", "mx-12", "mx-16", "mx-20", "mx-24", "mx-32", "mx-40", "mx-48", "mx-56", "mx-64", "mx-72", "mx-80", "mx-96", "mx-auto", "mx-full", "mx-1/2", "mx-1/3", "mx-2/3", "mx-1/4", "mx-2/4", "mx-3/4", "mx-1/5", "mx-2/5", "mx-3/5", "mx-4/5", "mx-1/6", "mx-5/6", "mx-1/12" ],
+        [ "my-0", "my-1", "my-2", "my-3", "my-4", "my-5", "my-6", "my-8", "my-10", "my-12", "my-16", "my-20", "my-24", "my-32", "my-40", "my-48", "my-56", "my-64", "my-72", "my-80", "my-96", "my-auto", "my-full", "my-1/2", "my-1/3", "my-2/3", "my-1/4", "my-2/4", "my-3/4", "my-1/5", "my-2/5", "my-3/5", "my-4/5", "my-1/6", "my-5/6", "my-1/12" ],
+        [ "mt-0", "mt-1", "mt-2", "mt-3", "mt-4", "mt-5", "mt-6", "mt-8", "mt-10", "mt-12", "mt-16", "mt-20", "mt-24", "mt-32", "mt-40", "mt-48", "mt-56", "mt-64", "mt-72", "mt-80", "mt-96", "mt-auto", "mt-full", "mt-1/2", "mt-1/3", "mt-2/3", "mt-1/4", "mt-2/4", "mt-3/4", "mt-1/5", "mt-2/5", "mt-3/5", "mt-4/5", "mt-1/6", "mt-5/6", "mt-1/12" ],
+        [ "mr-0", "mr-1", "mr-2", "mr-3", "mr-4", "mr-5", "mr-6", "mr-8", "mr-10", "mr-12", "mr-16", "mr-20", "mr-24", "mr-32", "mr-40", "mr-48", "mr-56", "mr-64", "mr-72", "mr-80", "mr-96", "mr-auto", "mr-full", "mr-1/2", "mr-1/3", "mr-2/3", "mr-1/4", "mr-2/4", "mr-3/4", "mr-1/5", "mr-2/5", "mr-3/5", "mr-4/5", "mr-1/6", "mr-5/6", "mr-1/12" ],
+        [ "mb-0", "mb-1", "mb-2", "mb-3", "mb-4", "mb-5", "mb-6", "mb-8", "mb-10", "mb-12", "mb-16", "mb-20", "mb-24", "mb-32", "mb-40", "mb-48", "mb-56", "mb-64", "mb-72", "mb-80", "mb-96", "mb-auto", "mb-full", "mb-1/2", "mb-1/3", "mb-2/3", "mb-1/4", "mb-2/4", "mb-3/4", "mb-1/5", "mb-2/5", "mb-3/5", "mb-4/5", "mb-1/6", "mb-5/6", "mb-1/12" ],
+        [ "ml-0", "ml-1", "ml-2", "ml-3", "ml-4", "ml-5", "ml-6", "ml-8", "ml-10", "ml-12", "ml-16", "ml-20", "ml-24", "ml-32", "ml-40", "ml-48", "ml-56", "ml-64", "ml-72", "ml-80", "ml-96", "ml-auto", "ml-full", "ml-1/2", "ml-1/3", "ml-2/3", "ml-1/4", "ml-2/4", "ml-3/4", "ml-1/5", "ml-2/5", "ml-3/5", "ml-4/5", "ml-1/6", "ml-5/6", "ml-1/12" ],
+        // Border
+        [ "border", "border-0", "border-1", "border-2", "border-3", "border-4", "border-5", "border-6", "border-8", "border-10", "border-12", "border-16", "border-20", "border-24", "border-32" ],
+        // Border width
+        [ "border-t", "border-t-0", "border-t-1", "border-t-2", "border-t-3", "border-t-4", "border-t-5", "border-t-6", "border-t-8", "border-t-10", "border-t-12", "border-t-16", "border-t-20", "border-t-24", "border-t-32" ],
+        [ "border-r", "border-r-0", "border-r-1", "border-r-2", "border-r-3", "border-r-4", "border-r-5", "border-r-6", "border-r-8", "border-r-10", "border-r-12", "border-r-16", "border-r-20", "border-r-24", "border-r-32" ],
+        [ "border-b", "border-b-0", "border-b-1", "border-b-2", "border-b-3", "border-b-4", "border-b-5", "border-b-6", "border-b-8", "border-b-10", "border-b-12", "border-b-16", "border-b-20", "border-b-24", "border-b-32" ],
+        [ "border-l", "border-l-0", "border-l-1", "border-l-2", "border-l-3", "border-l-4", "border-l-5", "border-l-6", "border-l-8", "border-l-10", "border-l-12", "border-l-16", "border-l-20", "border-l-24", "border-l-32" ],
+        // Border radius
+        [ "rounded-none", "rounded-sm", "rounded-md", "rounded-lg", "rounded-xl", "rounded-2xl", "rounded-3xl", "rounded-full" ],
+        [ "rounded-t-none", "rounded-t-sm", "rounded-t-md", "rounded-t-lg", "rounded-t-xl", "rounded-t-2xl", "rounded-t-3xl", "rounded-t-full" ],
+        [ "rounded-r-none", "rounded-r-sm", "rounded-r-md", "rounded-r-lg", "rounded-r-xl", "rounded-r-2xl", "rounded-r-3xl", "rounded-r-full" ],
+        [ "rounded-b-none", "rounded-b-sm", "rounded-b-md", "rounded-b-lg", "rounded-b-xl", "rounded-b-2xl", "rounded-b-3xl", "rounded-b-full" ],
+        [ "rounded-l-none", "rounded-l-sm", "rounded-l-md", "rounded-l-lg", "rounded-l-xl", "rounded-l-2xl", "rounded-l-3xl", "rounded-l-full" ],
+        [ "rounded-tl-none", "rounded-tl-sm", "rounded-tl-md", "rounded-tl-lg", "rounded-tl-xl", "rounded-tl-2xl", "rounded-tl-3xl", "rounded-tl-full" ],
+        [ "rounded-tr-none", "rounded-tr-sm", "rounded-tr-md", "rounded-tr-lg", "rounded-tr-xl", "rounded-tr-2xl", "rounded-tr-3xl", "rounded-tr-full" ],
+        [ "rounded-br-none", "rounded-br-sm", "rounded-br-md", "rounded-br-lg", "rounded-br-xl", "rounded-br-2xl", "rounded-br-3xl", "rounded-br-full" ],
+        [ "rounded-bl-none", "rounded-bl-sm", "rounded-bl-md", "rounded-bl-lg", "rounded-bl-xl", "rounded-bl-2xl", "rounded-bl-3xl", "rounded-bl-full" ],
+        // Font
+        [ "font-thin", "font-extralight", "font-light", "font-normal", "font-medium", "font-semibold", "font-bold", "font-extrabold", "font-black" ],
+        // Font family
+        [ ("font-sans", font(FONT_SANS)), ("font-serif", font(FONT_SERIF)), ("font-mono", font(FONT_MONO)) ],
+        // Text. GPUI has named sizes only up to text-3xl
+        [ "text-xs", "text-sm", "text-base", "text-lg", "text-xl", "text-2xl", "text-3xl", ("text-4xl", text_size(rems(2.25))) ],
+        // Sizes
+        [ "size-0", "size-0.5", "size-1", "size-1.5", "size-2", "size-2.5", "size-3", "size-3.5", "size-4", "size-5", "size-6", "size-8", "size-10", "size-12", "size-16", "size-20", "size-24", "size-32", "size-40", "size-48", "size-56", "size-64", "size-72", "size-80", "size-96", "size-1/2", "size-1/3", "size-2/3", "size-1/4", "size-2/4", "size-3/4", "size-1/5", "size-2/5", "size-3/5", "size-4/5", "size-1/6", "size-5/6", "size-1/12", "size-full", "size-auto" ],
+
+        // Dynamic sizes and colors
+        _ => {
+            // Handle dynamic background colors
+            if let Some(hex) = class_name
+                .strip_prefix("bg-[#")
+                .map(|hex| hex.trim_end_matches(']'))
+            {
+                let color = hex_to_rgba(hex);
+                element.bg(color)
+            }
+            // Handle dynamic text colors
+            else if let Some(hex) = class_name
+                .strip_prefix("text-color-[#")
+                .map(|hex| hex.trim_end_matches(']'))
+            {
+                let color = hex_to_rgba(hex);
+                element.text_color(color)
+            }
+            // Text size with any px or rem value. Doesn't clash with "text-color-[" above
+            else if let Some(suffix) = class_name.strip_prefix("text-[") {
+                element.text_size(absolute_length(extract_length_from_class_name(suffix)))
+            }
+            // Text is aligned by render_component, see align_text
+            else if matches!(class_name, "text-left" | "text-center" | "text-right" | "text-justify") {
+                element
+            }
+            // Font family by name, with _ for spaces: font-family-[Roboto_Mono] is "Roboto Mono"
+            else if let Some(name) = class_name
+                .strip_prefix("font-family-[")
+                .and_then(|name| name.strip_suffix(']'))
+            {
+                element.font(name.replace('_', " "))
+            }
+            // Text styles
+            else if class_name == "italic" {
+                with_text_style(element, |text| text.font_style = Some(FontStyle::Italic))
+            }
+            else if class_name == "not-italic" {
+                with_text_style(element, |text| text.font_style = Some(FontStyle::Normal))
+            }
+            else if class_name == "underline" {
+                with_text_style(element, |text| {
+                    text.underline = Some(UnderlineStyle {
+                        thickness: px(1.0),
+                        ..Default::default()
+                    })
+                })
+            }
+            // Zero thickness overrides an underline set earlier, e.g. by a parent
+            else if class_name == "no-underline" {
+                with_text_style(element, |text| {
+                    text.underline = Some(UnderlineStyle {
+                        thickness: px(0.0),
+                        ..Default::default()
+                    })
+                })
+            }
+            // TODO: GPUI has no strikethrough or text-transform yet
+            else if class_name == "line-through" {
+                tracing::warn!("line-through not yet supported by GPUI");
+                element
+            }
+            else if matches!(class_name, "uppercase" | "lowercase" | "capitalize") {
+                tracing::warn!("text-transform not yet supported by GPUI");
+                element
+            }
+            // Text in GPUI divs can't be selected with the mouse, so select-none is what every
+            // element already does
+            else if class_name == "select-none" {
+                element
+            }
+            else if matches!(class_name, "select-text" | "select-all" | "select-auto") {
+                tracing::warn!("{} not yet supported by GPUI, text can't be selected", class_name);
+                element
+            }
+            // GPUI can't clamp lines, so the line height is fixed and the max height cuts the
+            // text after N full lines. There is no ellipsis, like with truncate
+            else if let Some(value) = class_name.strip_prefix("line-clamp-") {
+                let value = value.trim_start_matches('[').trim_end_matches(']');
+                match value.parse::<u32>() {
+                    Ok(lines) if lines > 0 => {
+                        let line_height = AbsoluteLength::Rems(rems(LINE_CLAMP_LINE_HEIGHT));
+                        with_text_style(element, |text| {
+                            text.line_height = Some(DefiniteLength::Absolute(line_height))
+                        })
+                        .overflow_hidden()
+                        .max_h(AbsoluteLength::Rems(rems(LINE_CLAMP_LINE_HEIGHT * lines as f32)))
+                    }
+                    _ if value == "none" => {
+                        let mut element = element;
+                        element.style().max_size.height = None;
+                        element
+                    }
+                    _ => return Err(element),
+                }
+            }
+            else if matches!(class_name, "whitespace-pre" | "whitespace-pre-wrap" | "whitespace-pre-line" | "text-ellipsis" | "overflow-ellipsis") {
+                tracing::warn!("{} not yet supported by GPUI", class_name);
+                element
+            }
+            // Old spellings from when the macro could only derive the method from the class name
+            else if matches!(class_name, "flex-col_reverse" | "flex-row_reverse") {
+                tracing::warn!("{} is deprecated, use {}", class_name, class_name.replace('_', "-"));
+                if class_name == "flex-col_reverse" {
+                    element.flex_col_reverse()
+                } else {
+                    element.flex_row_reverse()
+                }
+            }
+            // Gradients are painted by render_component, see gradient_from_attributes
+            else if class_name
+                .strip_prefix("bg-gradient-to-")
+                .and_then(GradientDirection::parse)
+                .is_some()
+                || ["from-", "via-", "to-"]
+                    .iter()
+                    .find_map(|prefix| class_name.strip_prefix(prefix))
+                    .and_then(gradient_color)
+                    .is_some()
+            {
+                element
+            }
+            // Theme colors are set by set_attributes, which has the theme, see apply_theme_class
+            else if theme_class_token(class_name).is_some() {
+                element
+            }
+            // Filters are set by render_component, see Filter
+            else if Filter::default().apply_class(class_name).is_some() {
+                element
+            }
+            // Transforms are set by render_component, see Transform
+            else if Transform::default().apply_class(class_name).is_some() {
+                element
+            }
+            // Images get these from render_component, see object_fit_from_attributes
+            else if OBJECT_CLASSES.contains(&class_name) || class_name.starts_with("object-[") {
+                element
+            }
+            // Set by render_component, see PointerEvents
+            else if matches!(class_name, "pointer-events-none" | "pointer-events-auto") {
+                element
+            }
+            // grid-cols-N and col-span-N are read by render_component, see grid_columns
+            else if class_name.strip_prefix("grid-cols-").and_then(parse_columns).is_some() {
+                element
+            }
+            else if ["col-span-", "row-span-"]
+                .iter()
+                .find_map(|prefix| class_name.strip_prefix(prefix))
+                .and_then(parse_span)
+                .is_some()
+            {
+                element
+            }
+            // Aspect ratio. Styled has no method for it, so the ratio is set on the style refinement directly
+            else if class_name == "aspect-auto" {
+                let mut element = element;
+                element.style().aspect_ratio = None;
+                element
+            }
+            else if let Some(ratio) = aspect_ratio_from_class_name(class_name) {
+                let mut element = element;
+                element.style().aspect_ratio = Some(ratio);
+                element
+            }
+            // z-index, e.g. z-10 or z-[5]. z-auto is the default stacking
+            else if class_name == "z-auto" {
+                element
+            }
+            else if let Some(z_index) = z_index_from_class_name(class_name) {
+                element.z_index(z_index)
+            }
+            // Opacity, e.g. opacity-50, opacity-[35] or opacity-[0.35]
+            else if let Some(opacity) = opacity_from_class_name(class_name) {
+                element.opacity(opacity)
+            }
+            // Min and max sizes with any px, rem or % value, e.g. min-w-[240px] or max-h-[50%]
+            else if let Some((constraint, value)) = ["min-w-[", "max-w-[", "min-h-[", "max-h-["]
+                .iter()
+                .find_map(|prefix| class_name.strip_prefix(prefix).map(|value| (*prefix, value)))
+            {
+                let length = extract_length_from_class_name(value);
+                match constraint {
+                    "min-w-[" => element.min_w(length),
+                    "max-w-[" => element.max_w(length),
+                    "min-h-[" => element.min_h(length),
+                    _ => element.max_h(length),
+                }
+            }
+            // Positions not in the static lists (top-px, top-[12px]...) and the inset shorthands
+            else if let Some((side, value)) = ["inset-x-", "inset-y-", "inset-", "top-", "right-", "bottom-", "left-"]
+                .iter()
+                .find_map(|prefix| class_name.strip_prefix(prefix).map(|value| (*prefix, value)))
+            {
+                match tailwind_length(value) {
+                    Some(length) => match side {
+                        "top-" => element.top(length),
+                        "right-" => element.right(length),
+                        "bottom-" => element.bottom(length),
+                        "left-" => element.left(length),
+                        "inset-x-" => element.left(length).right(length),
+                        "inset-y-" => element.top(length).bottom(length),
+                        _ => element.top(length).right(length).bottom(length).left(length),
+                    },
+                    None => return Err(element),
+                }
+            }
+            // Handle dynamic border colors
+            else if let Some(hex) = class_name
+                .strip_prefix("border-[#")
+                .map(|hex| hex.trim_end_matches(']'))
+            {
+                let color = hex_to_rgba(hex);
+                element.border_color(color)
+            }
+            // Shadow color, e.g. shadow-color-[#0000aa80]. Only recolors the shadow set by a size
+            // class like shadow-lg, without one there is no shadow to color
+            else if let Some(hex) = class_name.strip_prefix("shadow-color-[#") {
+                let color: Hsla = hex_to_rgba(hex.trim_end_matches(']')).into();
+                let mut element = element;
+                if let Some(shadows) = element.style().box_shadow.as_mut() {
+                    for shadow in shadows.iter_mut() {
+                        shadow.color = color;
+                    }
+                }
+                element
+            }
+            // TODO: GPUI has no inset shadows yet
+            else if class_name == "shadow-inner" {
+                tracing::warn!("shadow-inner not yet supported by GPUI");
+                element
+            }
+            // Same with the longer name, e.g. border-color-[#ff000080]
+            else if let Some(hex) = class_name.strip_prefix("border-color-[#") {
+                let color = hex_to_rgba(hex.trim_end_matches(']'));
+                element.border_color(color)
+            }
+            // Named colors from the Tailwind palette, e.g. border-red-500
+            else if let Some(color) = class_name.strip_prefix("border-").and_then(tailwind_color) {
+                element.border_color(color)
+            }
+            // Rounded with any px or rem value
+            else if let Some(suffix) = class_name.strip_prefix("rounded-") {
+                let absolute_length = absolute_length(extract_length_from_class_name(suffix));
+
+                match suffix.split('-').next() {
+                    Some("t") => element.rounded_t(absolute_length),
+                    Some("r") => element.rounded_r(absolute_length),
+                    Some("b") => element.rounded_b(absolute_length),
+                    Some("l") => element.rounded_l(absolute_length),
+                    Some("tl") => element.rounded_tl(absolute_length),
+                    Some("tr") => element.rounded_tr(absolute_length),
+                    Some("br") => element.rounded_br(absolute_length),
+                    Some("bl") => element.rounded_bl(absolute_length),
+                    _ => element.rounded(absolute_length), // Default to applying rounding to all corners
+                }
+            }
+            // Border with any px or rem value
+            else if let Some(suffix) = class_name.strip_prefix("border-") {
+                let absolute_length = absolute_length(extract_length_from_class_name(suffix));
+                match suffix.split('-').next() {
+                    Some("t") => element.border_t_width(absolute_length),
+                    Some("r") => element.border_r_width(absolute_length),
+                    Some("b") => element.border_b_width(absolute_length),
+                    Some("l") => element.border_l_width(absolute_length),
+                    _ => element.rounded(absolute_length), // Default to applying rounding to all corners
+                }
+            }
+            else {
+                return Err(element);
+            }
+        }
+    ))
+}
+
+fn with_text_style<T: Styled>(mut element: T, f: impl FnOnce(&mut TextStyleRefinement)) -> T {
+    f(element.text_style().get_or_insert_with(Default::default));
+    element
+}
+
+// Parses opacity-N (percent) and opacity-[N] where N is a percent or a 0.0-1.0 decimal.
+// Kept separate so state modifiers like hover:opacity-75 can reuse it
+fn opacity_from_class_name(class_name: &str) -> Option<f32> {
+    let value = class_name.strip_prefix("opacity-")?;
+
+    let opacity = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(value) if value.contains('.') => value.parse::<f32>().ok()?,
+        Some(value) => value.parse::<f32>().ok()? / 100.0,
+        None => value.parse::<u32>().ok()? as f32 / 100.0,
+    };
+
+    if !(0.0..=1.0).contains(&opacity) {
+        tracing::warn!("{} is out of range, clamping to 0-100%", class_name);
+    }
+    Some(opacity.clamp(0.0, 1.0))
+}
+
+// Parses aspect-square, aspect-video and aspect-[W/H] to a width / height ratio
+fn aspect_ratio_from_class_name(class_name: &str) -> Option<f32> {
+    match class_name.strip_prefix("aspect-")? {
+        "square" => Some(1.0),
+        "video" => Some(16.0 / 9.0),
+        value => {
+            let value = value.strip_prefix('[')?.strip_suffix(']')?;
+            let (width, height) = value.split_once('/')?;
+            let width = width.trim().parse::<f32>().ok()?;
+            let height = height.trim().parse::<f32>().ok()?;
+            if width <= 0.0 || height <= 0.0 {
+                tracing::warn!("{} is not a valid aspect ratio", class_name);
+                return None;
+            }
+            Some(width / height)
+        }
+    }
+}
+
+// Parses z-N and z-[N]. GPUI takes the z-index as u8, so negative and too large values are clamped
+fn z_index_from_class_name(class_name: &str) -> Option<u8> {
+    let value = class_name.strip_prefix("z-")?;
+    let value = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .unwrap_or(value);
+    let z_index = value.parse::<i32>().ok()?;
+
+    match u8::try_from(z_index) {
+        Ok(z_index) => Some(z_index),
+        Err(_) => {
+            tracing::warn!("{} is out of range, clamping to 0-{}", class_name, u8::MAX);
+            Some(z_index.clamp(0, u8::MAX as i32) as u8)
+        }
+    }
+}
+
+// Converts a Tailwind spacing value ("4", "px", "1/2", "full", "auto" or "[12px]") to a Length
+fn tailwind_length(value: &str) -> Option<Length> {
+    if value.starts_with('[') {
+        return Some(extract_length_from_class_name(value));
+    }
+
+    match value {
+        "px" => Some(AbsoluteLength::Pixels(px(1.0)).into()),
+        "auto" => Some(Length::Auto),
+        "full" => Some(relative(1.0).into()),
+        _ => {
+            if let Some((numerator, denominator)) = value.split_once('/') {
+                let numerator = numerator.parse::<f32>().ok()?;
+                let denominator = denominator.parse::<f32>().ok()?;
+                Some(relative(numerator / denominator).into())
+            } else {
+                // One step of the spacing scale is 0.25rem
+                let steps = value.parse::<f32>().ok()?;
+                Some(AbsoluteLength::Rems(rems(steps / 4.0)).into())
+            }
+        }
+    }
+}
+
+// Extracts the numeric value and unit from the class name, returning a Length.
+// The value may have a prefix and brackets, e.g. "12px", "[12px]" or "t-[12px]".
+// GPUI has no viewport units, so vh and vw are fractions of the parent like %
+fn extract_length_from_class_name(class_name: &str) -> Length {
+    let value = class_name.trim_end_matches(']');
+    let value = &value[value
+        .find(|c: char| c.is_digit(10) || c == '.')
+        .unwrap_or(value.len())..];
+
+    let numeric_part: String = value
+        .chars()
+        .take_while(|c| c.is_digit(10) || *c == '.')
+        .collect();
+
+    let unit_part = &value[numeric_part.len()..];
+
+    let rounded_value = numeric_part.parse::<f32>().unwrap_or_default();
+
+    match unit_part {
+        "px" => AbsoluteLength::Pixels(px(rounded_value)).into(),
+        "rem" => AbsoluteLength::Rems(rems(rounded_value)).into(),
+        "%" | "vh" | "vw" => relative(rounded_value / 100.0).into(),
+        _ => AbsoluteLength::Pixels(px(0.0)).into(), // Default case for unrecognized units
+    }
+}
+
+// For the places that need a definite size, like gaps
+fn definite_length(length: Length) -> DefiniteLength {
+    match length {
+        Length::Definite(length) => length,
+        Length::Auto => px(0.0).into(),
+    }
+}
+
+// Text sizes, borders and radii can't be a fraction of the parent
+fn absolute_length(length: Length) -> AbsoluteLength {
+    match length {
+        Length::Definite(DefiniteLength::Absolute(length)) => length,
+        _ => {
+            tracing::warn!("Only px and rem lengths are supported here, using 0px");
+            px(0.0).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Error inside the Located wrapper parse_xml puts around errors
+    fn parse_error(xml: &str) -> ParseError {
+        match parse_xml(xml.to_string()) {
+            Ok(component) => panic!("{} parsed as {:?}", xml, component),
+            Err(ParseError::Located { error, .. }) => *error,
+            Err(error) => error,
+        }
+    }
+
+    // Empty directory for test files, removed by the test
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("xml2gpui-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn mismatched_end_tag_is_xml_error() {
+        let error = parse_error("<div><text>Hello</div>");
+        assert!(matches!(error, ParseError::XmlError(_)), "{:?}", error);
+    }
+
+    #[test]
+    fn bad_attribute_is_attribute_decode_error() {
+        let error = parse_error(r#"<div class="&bogus;"></div>"#);
+        assert!(
+            matches!(error, ParseError::AttributeDecodeError(_)),
+            "{:?}",
+            error
+        );
+    }
+
+    #[test]
+    fn bad_escape_in_text_is_unescape_error() {
+        let error = parse_error("<text>Fish &chips;</text>");
+        assert!(matches!(error, ParseError::UnescapeError(_)), "{:?}", error);
+    }
+
+    #[test]
+    fn no_root_element_is_empty_stack() {
+        for xml in ["", "<!-- nothing here -->"] {
+            let error = parse_error(xml);
+            assert!(matches!(error, ParseError::EmptyStack), "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn missing_include_is_io_error() {
+        let dir = test_dir("missing-include");
+        let root = dir.join("root.gpuiml");
+        std::fs::write(&root, r#"<div><include src="missing.gpuiml"/></div>"#).unwrap();
+
+        let error = parse_file(&root).unwrap_err();
+        assert!(
+            matches!(&error, ParseError::Io(path, _) if path.ends_with("missing.gpuiml")),
+            "{:?}",
+            error
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn circular_include_is_detected() {
+        let dir = test_dir("circular-include");
+        std::fs::write(
+            dir.join("a.gpuiml"),
+            r#"<div><include src="b.gpuiml"/></div>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("b.gpuiml"),
+            r#"<div><include src="a.gpuiml"/></div>"#,
+        )
+        .unwrap();
+
+        let mut context = ParseContext::default();
+        let error = parse_file_with_context(&dir.join("a.gpuiml"), &mut context).unwrap_err();
+        assert!(
+            matches!(&error, ParseError::CircularInclude(path) if path.ends_with("a.gpuiml")),
+            "{:?}",
+            error
+        );
+        // Both files are watched, so fixing either one reloads
+        assert_eq!(context.loaded_files().count(), 2);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn include_is_replaced_by_included_root() {
+        let dir = test_dir("include");
+        std::fs::write(
+            dir.join("root.gpuiml"),
+            r#"<div><include src="child.gpuiml"/></div>"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("child.gpuiml"), "<text>Included</text>").unwrap();
+
+        let root = parse_file(&dir.join("root.gpuiml")).unwrap();
+        assert_eq!(root.children.len(), 1);
+        assert_eq!(root.children[0].elem, "text");
+        assert_eq!(root.children[0].text.as_deref(), Some("Included"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn errors_are_located() {
+        let xml = "<div>\n  <text>One</text>\n  <text>Two</span>\n  <text>Three</text>\n</div>";
+        let Err(ParseError::Located {
+            path,
+            line,
+            column,
+            snippet,
+            error,
+        }) = parse_xml(xml.to_string())
+        else {
+            panic!("expected a located error");
+        };
+        assert!(matches!(*error, ParseError::XmlError(_)), "{:?}", error);
+        assert_eq!(path, None);
+        assert_eq!(line, 3);
+        assert!(column > 1);
+        assert_eq!(
+            snippet,
+            "   1 | <div>\n   2 |   <text>One</text>\n   3 |   <text>Two</span>"
+        );
+    }
+
+    #[test]
+    fn errors_in_files_have_the_path() {
+        let dir = test_dir("located-file");
+        let root = dir.join("root.gpuiml");
+        std::fs::write(&root, "<div>\n<text>Oops</div>").unwrap();
+
+        let error = parse_file(&root).unwrap_err();
+        let ParseError::Located { path, line, .. } = &error else {
+            panic!("expected a located error, got {:?}", error);
+        };
+        assert_eq!(
+            path.as_deref(),
+            Some(root.canonicalize().unwrap().as_path())
+        );
+        assert_eq!(*line, 2);
+        assert!(error.to_string().contains("root.gpuiml:2:"), "{}", error);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn to_xml_round_trips() {
+        let trees = [
+            ComponentBuilder::elem("div").build(),
+            ComponentBuilder::elem("text").text("Hello").build(),
+            ComponentBuilder::elem("div")
+                .attr("class", "flex flex-col gap-4")
+                .attr("id", "root")
+                .child(
+                    ComponentBuilder::elem("button")
+                        .attr("on:click", "save_config")
+                        .text("Save")
+                        .build(),
+                )
+                .child(
+                    ComponentBuilder::elem("div")
+                        .child(
+                            ComponentBuilder::elem("input")
+                                .attr("type", "text")
+                                .attr("bind", "2001")
+                                .build(),
+                        )
+                        .child(
+                            ComponentBuilder::elem("img")
+                                .attr("src", "logo.svg")
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+            // Characters that have to be escaped
+            ComponentBuilder::elem("text")
+                .attr("title", r#"Fish & "chips" <cheap>"#)
+                .text("1 < 2 && 3 > 2")
+                .build(),
+        ];
+
+        for tree in trees {
+            let xml = tree.to_xml();
+            assert_eq!(parse_xml(xml.clone()).unwrap(), tree, "{}", xml);
+        }
+    }
+
+    // <div id="root">
+    //   <div id="form"><text id="title"/><div id="inner"><text/></div></div>
+    //   <text id="footer"/>
+    // </div>
+    fn find_tree() -> Component {
+        ComponentBuilder::elem("div")
+            .attr("id", "root")
+            .child(
+                ComponentBuilder::elem("div")
+                    .attr("id", "form")
+                    .child(ComponentBuilder::elem("text").attr("id", "title").build())
+                    .child(
+                        ComponentBuilder::elem("div")
+                            .attr("id", "inner")
+                            .child(ComponentBuilder::elem("text").build())
+                            .build(),
+                    )
+                    .build(),
+            )
+            .child(ComponentBuilder::elem("text").attr("id", "footer").build())
+            .build()
+    }
+
+    #[test]
+    fn find_by_id_searches_the_whole_tree() {
+        let mut tree = find_tree();
+        assert_eq!(tree.find_by_id("root").unwrap().number, 1);
+        assert_eq!(tree.find_by_id("inner").unwrap().elem, "div");
+        assert_eq!(tree.find_by_id("footer").unwrap().elem, "text");
+        assert!(tree.find_by_id("missing").is_none());
+
+        tree.find_by_id_mut("title").unwrap().text = Some("Settings".to_string());
+        assert_eq!(
+            tree.find_by_id("title").unwrap().text.as_deref(),
+            Some("Settings")
+        );
+        assert!(tree.find_by_id_mut("missing").is_none());
+    }
+
+    #[test]
+    fn find_by_elem_returns_the_first_in_depth_first_order() {
+        let mut tree = find_tree();
+        assert_eq!(
+            tree.find_by_id("form")
+                .unwrap()
+                .find_by_elem("div")
+                .unwrap()
+                .number,
+            2
+        );
+        let text = tree.find_by_elem("text").unwrap();
+        assert_eq!(text.attributes, [("id".to_string(), "title".to_string())]);
+        assert!(tree.find_by_elem("img").is_none());
+        assert_eq!(tree.find_by_elem_mut("text").unwrap().number, 3);
+    }
+
+    #[test]
+    fn find_all_includes_nested_matches_and_find_all_mut_does_not() {
+        let mut tree = find_tree();
+        let numbers = |found: Vec<&Component>| found.iter().map(|c| c.number).collect::<Vec<_>>();
+        assert_eq!(numbers(tree.find_all("div")), [1, 2, 4]);
+        assert_eq!(numbers(tree.find_all("text")), [3, 5, 6]);
+        assert!(tree.find_all("img").is_empty());
+
+        // All divs are nested in the root div
+        let outermost: Vec<i32> = tree.find_all_mut("div").iter().map(|c| c.number).collect();
+        assert_eq!(outermost, [1]);
+        // None of the texts are nested in another text
+        for text in tree.find_all_mut("text") {
+            text.text = Some("Changed".to_string());
+        }
+        assert!(tree
+            .find_all("text")
+            .iter()
+            .all(|text| text.text.as_deref() == Some("Changed")));
+    }
+
+    #[test]
+    fn lengths_from_class_names() {
+        assert_eq!(extract_length_from_class_name("16px"), px(16.0).into());
+        assert_eq!(extract_length_from_class_name("[1.5rem]"), rems(1.5).into());
+        assert_eq!(extract_length_from_class_name("t-[12px]"), px(12.0).into());
+        assert_eq!(extract_length_from_class_name("75%"), relative(0.75).into());
+        assert_eq!(extract_length_from_class_name("50vh"), relative(0.5).into());
+        assert_eq!(
+            extract_length_from_class_name("[25vw]"),
+            relative(0.25).into()
+        );
+        // Unknown units are 0px
+        assert_eq!(extract_length_from_class_name("[12em]"), px(0.0).into());
+    }
+
+    #[test]
+    fn tailwind_lengths() {
+        assert_eq!(tailwind_length("4"), Some(rems(1.0).into()));
+        assert_eq!(tailwind_length("0.5"), Some(rems(0.125).into()));
+        assert_eq!(tailwind_length("px"), Some(px(1.0).into()));
+        assert_eq!(tailwind_length("auto"), Some(Length::Auto));
+        assert_eq!(tailwind_length("full"), Some(relative(1.0).into()));
+        assert_eq!(tailwind_length("1/2"), Some(relative(0.5).into()));
+        assert_eq!(tailwind_length("[12px]"), Some(px(12.0).into()));
+        assert_eq!(tailwind_length("large"), None);
+        assert_eq!(tailwind_length("1/x"), None);
+    }
+
+    #[test]
+    fn amounts() {
+        assert_eq!(parse_amount("150"), Some(1.5));
+        assert_eq!(parse_amount("[0.25]"), Some(0.25));
+        assert_eq!(parse_amount("[0.25"), None);
+        assert_eq!(parse_amount("half"), None);
+    }
+
+    #[test]
+    fn angles() {
+        let assert_radians = |value: &str, expected: f32| {
+            let radians = parse_angle(value).unwrap();
+            assert!((radians - expected).abs() < 1e-6, "{}: {}", value, radians);
+        };
+        assert_radians("45", std::f32::consts::FRAC_PI_4);
+        assert_radians("[90deg]", std::f32::consts::FRAC_PI_2);
+        assert_radians("[0.5turn]", std::f32::consts::PI);
+        assert_radians("[1.2rad]", 1.2);
+        assert_eq!(parse_angle("[45grad]"), None);
+        assert_eq!(parse_angle("[45deg"), None);
+        assert_eq!(parse_angle("left"), None);
+    }
+
+    #[test]
+    fn translations() {
+        assert_eq!(parse_translation("px", 1.0), Some(px(1.0).into()));
+        assert_eq!(parse_translation("4", 1.0), Some(rems(1.0).into()));
+        assert_eq!(parse_translation("2", -1.0), Some(rems(-0.5).into()));
+        assert_eq!(parse_translation("[12px]", -1.0), Some(px(-12.0).into()));
+        assert_eq!(parse_translation("[1.5rem]", 1.0), Some(rems(1.5).into()));
+        assert_eq!(parse_translation("[50%]", 1.0), None);
+        assert_eq!(parse_translation("full", 1.0), None);
+    }
+
+    #[test]
+    fn opacities() {
+        assert_eq!(opacity_from_class_name("opacity-50"), Some(0.5));
+        assert_eq!(opacity_from_class_name("opacity-[35]"), Some(0.35));
+        assert_eq!(opacity_from_class_name("opacity-[0.35]"), Some(0.35));
+        // Out of range values are clamped
+        assert_eq!(opacity_from_class_name("opacity-150"), Some(1.0));
+        assert_eq!(opacity_from_class_name("opacity-half"), None);
+        assert_eq!(opacity_from_class_name("bg-opacity-50"), None);
+    }
+
+    #[test]
+    fn z_indexes() {
+        assert_eq!(z_index_from_class_name("z-10"), Some(10));
+        assert_eq!(z_index_from_class_name("z-[5]"), Some(5));
+        // GPUI's z-index is u8
+        assert_eq!(z_index_from_class_name("z-[-1]"), Some(0));
+        assert_eq!(z_index_from_class_name("z-1000"), Some(u8::MAX));
+        assert_eq!(z_index_from_class_name("z-auto"), None);
+    }
+
+    #[test]
+    fn aspect_ratios() {
+        assert_eq!(aspect_ratio_from_class_name("aspect-square"), Some(1.0));
+        assert_eq!(
+            aspect_ratio_from_class_name("aspect-video"),
+            Some(16.0 / 9.0)
+        );
+        assert_eq!(
+            aspect_ratio_from_class_name("aspect-[4/3]"),
+            Some(4.0 / 3.0)
+        );
+        assert_eq!(aspect_ratio_from_class_name("aspect-[ 3 / 2 ]"), Some(1.5));
+        assert_eq!(aspect_ratio_from_class_name("aspect-[4/0]"), None);
+        assert_eq!(aspect_ratio_from_class_name("aspect-[4:3]"), None);
+        assert_eq!(aspect_ratio_from_class_name("aspect-auto"), None);
+    }
+
+    #[test]
+    fn hex_colors() {
+        assert_eq!(hex_to_rgba("0055ff"), rgba(0x0055ffff));
+        assert_eq!(hex_to_rgba("#0055ff"), rgba(0x0055ffff));
+        assert_eq!(hex_to_rgba("0055ff80"), rgba(0x0055ff80));
+        assert_eq!(hex_to_rgba("abcdef"), hex_to_rgba("ABCDEF"));
+        // Invalid colors are transparent
+        for hex in ["", "0055f", "0055ff8", "00gg00", "+12345", "#0055ff]"] {
+            assert_eq!(hex_to_rgba(hex), rgba(0x00000000), "{}", hex);
+        }
+    }
+
+    #[test]
+    fn hex_color_classes() {
+        for class_name in ["bg-[#0055ff]", "text-color-[#0055ff80]", "border-[#0055ff]"] {
+            assert!(try_apply_class(StyleRefinement::default(), class_name).is_ok());
+        }
+        // Incomplete classes are transparent instead of a panic
+        for class_name in ["bg-[#", "text-color-[#", "border-[#", "bg-[#]"] {
+            assert!(try_apply_class(StyleRefinement::default(), class_name).is_ok());
+        }
+    }
+
+    // Style of a class from try_apply_class, as Debug output to compare
+    fn class_style(class_name: &str) -> String {
+        match try_apply_class(StyleRefinement::default(), class_name) {
+            Ok(style) => format!("{:?}", style),
+            Err(_) => panic!("unknown class {}", class_name),
+        }
+    }
+
+    #[test]
+    fn generated_class_arms() {
+        let classes: [(&str, fn(StyleRefinement) -> StyleRefinement); 50] = [
+            ("flex", |style| style.flex()),
+            ("flex-grow", |style| style.flex_grow()),
+            ("flex-shrink-0", |style| style.flex_shrink_0()),
+            ("grow", |style| style.flex_grow()),
+            ("shrink", |style| style.flex_shrink()),
+            ("shrink-0", |style| style.flex_shrink_0()),
+            ("flex-col", |style| style.flex_col()),
+            ("flex-row", |style| style.flex_row()),
+            ("flex-col-reverse", |style| style.flex_col_reverse()),
+            ("flex-row-reverse", |style| style.flex_row_reverse()),
+            ("flex-1", |style| style.flex_1()),
+            ("flex-none", |style| style.flex_none()),
+            ("flex-wrap", |style| style.flex_wrap()),
+            ("content-between", |style| style.content_between()),
+            ("absolute", |style| style.absolute()),
+            ("relative", |style| style.relative()),
+            ("invisible", |style| style.invisible()),
+            ("overflow-hidden", |style| style.overflow_hidden()),
+            ("whitespace-nowrap", |style| style.whitespace_nowrap()),
+            ("items-center", |style| style.items_center()),
+            ("justify-between", |style| style.justify_between()),
+            ("top-0", |style| style.top_0()),
+            ("right-1/2", |style| style.right_1_2()),
+            ("left-auto", |style| style.left_auto()),
+            ("cursor-pointer", |style| style.cursor_pointer()),
+            ("shadow-lg", |style| style.shadow_lg()),
+            ("h-8", |style| style.h_8()),
+            ("h-full", |style| style.h_full()),
+            ("w-4", |style| style.w_4()),
+            ("w-1/3", |style| style.w_1_3()),
+            ("min-w-0", |style| style.min_w_0()),
+            ("max-h-full", |style| style.max_h_full()),
+            ("p-4", |style| style.p_4()),
+            ("px-2", |style| style.px_2()),
+            ("pt-1/2", |style| style.pt_1_2()),
+            ("m-auto", |style| style.m_auto()),
+            ("mx-auto", |style| style.mx_auto()),
+            ("mb-6", |style| style.mb_6()),
+            ("border", |style| style.border()),
+            ("border-2", |style| style.border_2()),
+            ("border-b-1", |style| style.border_b_1()),
+            ("rounded-md", |style| style.rounded_md()),
+            ("rounded-full", |style| style.rounded_full()),
+            ("rounded-tl-lg", |style| style.rounded_tl_lg()),
+            ("font-bold", |style| style.font_weight(FontWeight::BOLD)),
+            ("font-extralight", |style| {
+                style.font_weight(FontWeight::EXTRA_LIGHT)
+            }),
+            ("text-sm", |style| style.text_sm()),
+            ("text-2xl", |style| style.text_2xl()),
+            ("size-0.5", |style| style.size_0p5()),
+            ("size-full", |style| style.size_full()),
+        ];
+
+        for (class_name, method) in classes {
+            assert_eq!(
+                class_style(class_name),
+                format!("{:?}", method(StyleRefinement::default())),
+                "{}",
+                class_name
+            );
+        }
+        // The short names are the same as the long ones
+        assert_eq!(class_style("grow"), class_style("flex-grow"));
+        assert_eq!(class_style("shrink-0"), class_style("flex-shrink-0"));
+    }
+
+    // Whether try_apply_class knows the class
+    fn known_class(class_name: &str) -> bool {
+        try_apply_class(StyleRefinement::default(), class_name).is_ok()
+    }
+
+    // Style of a div-only class from try_apply_stateful_class, as Debug output to compare
+    fn stateful_class_style(class_name: &str) -> Option<String> {
+        try_apply_stateful_class(div().id("test"), class_name)
+            .ok()
+            .map(|mut element| format!("{:?}", element.style()))
+    }
+
+    #[test]
+    fn gap_classes() {
+        let debug = |refinement: StyleRefinement| Some(format!("{:?}", refinement));
+        let gaps: [(&str, StyleRefinement); 6] = [
+            ("gap-4", StyleRefinement::default().gap_4()),
+            ("gap-px", StyleRefinement::default().gap_px()),
+            ("gap-x-0.5", StyleRefinement::default().gap_x_0p5()),
+            ("gap-y-96", StyleRefinement::default().gap_y_96()),
+            ("gap-[12px]", StyleRefinement::default().gap(px(12.0))),
+            (
+                "gap-x-[1.5rem]",
+                StyleRefinement::default().gap_x(rems(1.5)),
+            ),
+        ];
+        for (class_name, expected) in gaps {
+            assert_eq!(
+                stateful_class_style(class_name),
+                debug(expected),
+                "{}",
+                class_name
+            );
+        }
+        for class_name in ["gap-7", "gap-x-seven", "gap-z-4"] {
+            assert_eq!(stateful_class_style(class_name), None, "{}", class_name);
+        }
+        // Gaps need children, so they aren't plain classes
+        assert!(!known_class("gap-4"));
+    }
+
+    #[test]
+    fn text_size_classes() {
+        assert_eq!(
+            class_style("text-[18px]"),
+            format!("{:?}", StyleRefinement::default().text_size(px(18.0)))
+        );
+        assert_eq!(
+            class_style("text-[1.25rem]"),
+            format!("{:?}", StyleRefinement::default().text_size(rems(1.25)))
+        );
+        assert_eq!(
+            class_style("text-4xl"),
+            format!("{:?}", StyleRefinement::default().text_size(rems(2.25)))
+        );
+        for class_name in ["text-5xl", "text-huge"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn grid_classes() {
+        assert_eq!(
+            class_style("grid"),
+            format!(
+                "{:?}",
+                StyleRefinement::default().flex().flex_row().flex_wrap()
+            )
+        );
+        for class_name in ["grid-cols-3", "col-span-2", "col-span-full", "row-span-2"] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        for class_name in [
+            "grid-cols-foo",
+            "grid-cols-0",
+            "grid-cols-13",
+            "col-span-0",
+            "row-span-x",
+        ] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn grid_columns_and_spans() {
+        let grid = |classes: &str| {
+            ComponentBuilder::elem("div")
+                .attr("class", classes)
+                .build()
+                .attributes
+        };
+        assert_eq!(grid_columns(&grid("grid grid-cols-3")), Some(3));
+        // Not a grid without the grid class, or with an unknown column count
+        assert_eq!(grid_columns(&grid("flex grid-cols-3")), None);
+        assert_eq!(grid_columns(&grid("grid grid-cols-foo")), None);
+
+        let child = |classes: &str| ComponentBuilder::elem("div").attr("class", classes).build();
+        assert_eq!(grid_span(&child("col-span-2"), 3), 2);
+        assert_eq!(grid_span(&child("col-span-full"), 3), 3);
+        assert_eq!(grid_span(&child("col-span-5"), 3), 3);
+        assert_eq!(grid_span(&child("col-span-x"), 3), 1);
+        assert_eq!(grid_span(&child("p-2"), 3), 1);
+    }
+
+    #[test]
+    fn transform_classes() {
+        let transform = |classes: &str| {
+            Transform::from_attributes(
+                &ComponentBuilder::elem("svg")
+                    .attr("class", classes)
+                    .build()
+                    .attributes,
+            )
+        };
+        let rotated = transform("scale-150 -rotate-[90deg] translate-x-4 -translate-y-[12px]");
+        assert_eq!(rotated.scale, Some(1.5));
+        assert!((rotated.rotate.unwrap() + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+        assert!(rotated.translate_x == Some(rems(1.0).into()));
+        assert!(rotated.translate_y == Some(px(-12.0).into()));
+        assert_eq!(
+            transform("rotate-[0.5turn]").rotate,
+            Some(std::f32::consts::PI)
+        );
+        assert!(transform("p-2").is_empty());
+
+        for class_name in ["scale-[0.5]", "-rotate-45", "translate-y-px"] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        for class_name in ["scale-abc", "rotate-x", "rotate-[45]", "translate-x-[50%]"] {
+            assert!(!known_class(class_name), "{}", class_name);
+            assert!(transform(class_name).is_empty(), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn filter_classes() {
+        let filter = |classes: &str| {
+            Filter::from_attributes(
+                &ComponentBuilder::elem("img")
+                    .attr("class", classes)
+                    .build()
+                    .attributes,
+            )
+        };
+        let filtered = filter("blur-lg brightness-50 contrast-[1.25] grayscale sepia");
+        assert_eq!(filtered.blur, Some(16.0));
+        assert_eq!(filtered.brightness, Some(0.5));
+        assert_eq!(filtered.contrast, Some(1.25));
+        assert!(filtered.grayscale && filtered.sepia && !filtered.invert);
+        assert_eq!(filter("blur-[2px]").blur, Some(2.0));
+        // Later classes win
+        assert_eq!(filter("blur blur-none").blur, None);
+        assert!(!filter("grayscale grayscale-0").grayscale);
+
+        for class_name in ["blur", "invert-0", "brightness-[1.5]"] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        for class_name in ["blur-xyz", "blur-[2rem]", "brightness-high", "contrast-[x]"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn gradient_classes() {
+        assert_eq!(gradient_color("[#0055ff]"), Some(rgb(0x0055ff)));
+        assert_eq!(gradient_color("blue-500"), tailwind_color("blue-500"));
+        assert_eq!(gradient_color("bannana"), None);
+
+        let gradient = |classes: &str| {
+            gradient_from_attributes(
+                &ComponentBuilder::elem("div")
+                    .attr("class", classes)
+                    .build()
+                    .attributes,
+            )
+        };
+        assert!(gradient("bg-gradient-to-r from-[#0055ff] to-blue-500").is_some());
+        // Colors without a direction are no gradient
+        assert!(gradient("from-[#0055ff] to-blue-500").is_none());
+        assert!(gradient("bg-gradient-to-up from-[#0055ff]").is_none());
+
+        for class_name in [
+            "bg-gradient-to-tl",
+            "from-red-500",
+            "via-[#ffffff]",
+            "to-blue-900",
+        ] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        for class_name in [
+            "bg-gradient-to-up",
+            "from-red",
+            "via-blue-450",
+            "to-bannana",
+        ] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn position_classes() {
+        assert_eq!(
+            class_style("top-px"),
+            format!("{:?}", StyleRefinement::default().top(px(1.0)))
+        );
+        assert_eq!(
+            class_style("left-[12px]"),
+            format!("{:?}", StyleRefinement::default().left(px(12.0)))
+        );
+        assert_eq!(
+            class_style("inset-x-2"),
+            format!(
+                "{:?}",
+                StyleRefinement::default().left(rems(0.5)).right(rems(0.5))
+            )
+        );
+        assert_eq!(
+            class_style("inset-0"),
+            format!(
+                "{:?}",
+                StyleRefinement::default()
+                    .top(rems(0.0))
+                    .right(rems(0.0))
+                    .bottom(rems(0.0))
+                    .left(rems(0.0))
+            )
+        );
+        for class_name in ["top-large", "inset-x-wide", "bottom-1/x"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn opacity_classes() {
+        assert_eq!(
+            class_style("opacity-75"),
+            format!("{:?}", StyleRefinement::default().opacity(0.75))
+        );
+        assert_eq!(
+            class_style("opacity-[0.35]"),
+            format!("{:?}", StyleRefinement::default().opacity(0.35))
+        );
+        for class_name in ["opacity-half", "opacity-[x]"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn text_alignment_classes() {
+        for class_name in ["text-left", "text-center", "text-right", "text-justify"] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        for class_name in ["text-middle", "text-start"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn font_weight_classes() {
+        let weights = [
+            ("font-thin", FontWeight::THIN),
+            ("font-extralight", FontWeight::EXTRA_LIGHT),
+            ("font-light", FontWeight::LIGHT),
+            ("font-normal", FontWeight::NORMAL),
+            ("font-medium", FontWeight::MEDIUM),
+            ("font-semibold", FontWeight::SEMIBOLD),
+            ("font-bold", FontWeight::BOLD),
+            ("font-extrabold", FontWeight::EXTRA_BOLD),
+            ("font-black", FontWeight::BLACK),
+        ];
+        for (class_name, weight) in weights {
+            assert_eq!(
+                class_style(class_name),
+                format!("{:?}", StyleRefinement::default().font_weight(weight)),
+                "{}",
+                class_name
+            );
+        }
+        assert!(!known_class("font-heavy"));
+    }
+
+    #[test]
+    fn text_style_classes() {
+        let text_style = |class_name: &str| {
+            let mut style = try_apply_class(StyleRefinement::default(), class_name).unwrap();
+            style.text_style().take().unwrap_or_default()
+        };
+        assert_eq!(text_style("italic").font_style, Some(FontStyle::Italic));
+        assert_eq!(text_style("not-italic").font_style, Some(FontStyle::Normal));
+        let thickness = |class_name| {
+            text_style(class_name)
+                .underline
+                .map(|underline| underline.thickness)
+        };
+        assert_eq!(thickness("underline"), Some(px(1.0)));
+        assert_eq!(thickness("no-underline"), Some(px(0.0)));
+        // Not supported by GPUI, but known
+        for class_name in ["line-through", "uppercase", "capitalize"] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        for class_name in ["italics", "underlined"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn whitespace_classes() {
+        assert_eq!(
+            class_style("truncate"),
+            format!(
+                "{:?}",
+                StyleRefinement::default()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+            )
+        );
+        assert_eq!(
+            class_style("whitespace-normal"),
+            format!("{:?}", StyleRefinement::default().whitespace_normal())
+        );
+        // Not supported by GPUI, but known
+        assert!(known_class("whitespace-pre-wrap"));
+        for class_name in ["whitespace-nope", "truncated"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn z_index_classes() {
+        assert_eq!(
+            class_style("z-10"),
+            format!("{:?}", StyleRefinement::default().z_index(10))
+        );
+        assert_eq!(
+            class_style("z-[5]"),
+            format!("{:?}", StyleRefinement::default().z_index(5))
+        );
+        assert_eq!(
+            class_style("z-auto"),
+            format!("{:?}", StyleRefinement::default())
+        );
+        for class_name in ["z-top", "z-[x]"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn space_classes() {
+        let spacing = |classes: &str| {
+            ChildSpacing::from_attributes(
+                &ComponentBuilder::elem("div")
+                    .attr("class", classes)
+                    .build()
+                    .attributes,
+            )
+        };
+        let spaced = spacing("flex space-x-4 space-y-[12px] space-y-reverse").unwrap();
+        assert_eq!(spaced.x, Some(rems(1.0).into()));
+        assert_eq!(spaced.y, Some(px(12.0).into()));
+        assert!(!spaced.reverse_x && spaced.reverse_y);
+        // Nothing to space without a length
+        assert!(spacing("space-x-reverse").is_none());
+        assert!(spacing("space-x-wide").is_none());
+
+        // The margins are set on the children, not on the div itself
+        let unstyled = Some(format!("{:?}", div().id("test").style()));
+        for class_name in ["space-x-px", "space-y-1/2", "space-x-reverse"] {
+            assert_eq!(stateful_class_style(class_name), unstyled, "{}", class_name);
+        }
+        for class_name in ["space-x-wide", "space-z-4"] {
+            assert_eq!(stateful_class_style(class_name), None, "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn border_color_classes() {
+        assert_eq!(
+            class_style("border-color-[#ff000080]"),
+            format!(
+                "{:?}",
+                StyleRefinement::default().border_color(rgba(0xff000080))
+            )
+        );
+        assert_eq!(
+            class_style("border-red-500"),
+            format!(
+                "{:?}",
+                StyleRefinement::default().border_color(tailwind_color("red-500").unwrap())
+            )
+        );
+    }
+
+    #[test]
+    fn shadow_color_class() {
+        let shadow = try_apply_class(StyleRefinement::default(), "shadow-lg")
+            .and_then(|style| try_apply_class(style, "shadow-color-[#0000aa80]"))
+            .unwrap();
+        let color: Hsla = rgba(0x0000aa80).into();
+        let shadows = shadow.box_shadow.unwrap();
+        assert!(!shadows.is_empty());
+        assert!(shadows.iter().all(|shadow| shadow.color == color));
+        // Without a shadow there is nothing to color
+        assert_eq!(
+            class_style("shadow-color-[#0000aa80]"),
+            format!("{:?}", StyleRefinement::default())
+        );
+    }
+
+    #[test]
+    fn font_family_classes() {
+        assert_eq!(
+            class_style("font-mono"),
+            format!("{:?}", StyleRefinement::default().font(FONT_MONO))
+        );
+        assert_eq!(
+            class_style("font-family-[Roboto_Mono]"),
+            format!("{:?}", StyleRefinement::default().font("Roboto Mono"))
+        );
+        for class_name in ["font-cursive", "font-family-[Roboto"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn min_max_size_classes() {
+        assert_eq!(
+            class_style("min-w-[240px]"),
+            format!("{:?}", StyleRefinement::default().min_w(px(240.0)))
+        );
+        assert_eq!(
+            class_style("max-h-[50%]"),
+            format!("{:?}", StyleRefinement::default().max_h(relative(0.5)))
+        );
+        assert_eq!(
+            class_style("max-w-[20rem]"),
+            format!("{:?}", StyleRefinement::default().max_w(rems(20.0)))
+        );
+        for class_name in ["min-w-large", "max-h-screen"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn line_clamp_classes() {
+        let mut clamped = try_apply_class(StyleRefinement::default(), "line-clamp-2").unwrap();
+        assert_eq!(
+            clamped.max_size.height,
+            Some(AbsoluteLength::Rems(rems(LINE_CLAMP_LINE_HEIGHT * 2.0)).into())
+        );
+        assert_eq!(
+            clamped
+                .text_style()
+                .as_ref()
+                .and_then(|text| text.line_height),
+            Some(DefiniteLength::Absolute(AbsoluteLength::Rems(rems(
+                LINE_CLAMP_LINE_HEIGHT
+            ))))
+        );
+        let unclamped = try_apply_class(clamped, "line-clamp-none").unwrap();
+        assert_eq!(unclamped.max_size.height, None);
+        assert!(known_class("line-clamp-[3]"));
+        for class_name in ["line-clamp-0", "line-clamp-x"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
+
+    #[test]
+    fn pointer_events_classes() {
+        let pointer_events = |classes: &str| {
+            PointerEvents::from_attributes(
+                &ComponentBuilder::elem("div")
+                    .attr("class", classes)
+                    .build()
+                    .attributes,
+            )
+        };
+        assert_eq!(pointer_events("p-2 pointer-events-none"), Some(false));
+        assert_eq!(
+            pointer_events("pointer-events-none pointer-events-auto"),
+            Some(true)
+        );
+        assert_eq!(pointer_events("p-2"), None);
+        assert!(known_class("pointer-events-none"));
+        assert!(!known_class("pointer-events-all"));
+    }
+
+    #[test]
+    fn object_fit_classes() {
+        let object_fit = |classes: &str| {
+            object_fit_from_attributes(
+                &ComponentBuilder::elem("img")
+                    .attr("class", classes)
+                    .build()
+                    .attributes,
+            )
+        };
+        assert!(matches!(
+            object_fit("object-contain"),
+            Some(ObjectFit::Contain)
+        ));
+        // The last one wins, positions don't change the fit
+        assert!(matches!(
+            object_fit("object-cover object-scale-down object-top"),
+            Some(ObjectFit::ScaleDown)
+        ));
+        assert!(object_fit("object-center").is_none());
+        for class_name in ["object-cover", "object-left-top", "object-[25%_75%]"] {
+            assert!(known_class(class_name), "{}", class_name);
+        }
+        assert!(!known_class("object-squash"));
+    }
+
+    #[test]
+    fn cursor_classes() {
+        let cursors = [
+            ("cursor-default", CursorStyle::Arrow),
+            ("cursor-pointer", CursorStyle::PointingHand),
+            ("cursor-text", CursorStyle::IBeam),
+            ("cursor-crosshair", CursorStyle::Crosshair),
+            ("cursor-grab", CursorStyle::OpenHand),
+        ];
+        for (class_name, cursor) in cursors {
+            assert_eq!(
+                class_style(class_name),
+                format!("{:?}", StyleRefinement::default().cursor(cursor)),
+                "{}",
+                class_name
+            );
+        }
+        // GPUI has no cursor for these, so they aren't mapped to a look-alike
+        for class_name in ["cursor-zoom-in", "cursor-wait", "cursor-help"] {
+            assert!(!known_class(class_name), "{}", class_name);
+        }
+    }
 
-    match unit_part.as_str() {
-        "px" => AbsoluteLength::Pixels(px(rounded_value)),
-        "rem" => AbsoluteLength::Rems(rems(rounded_value)),
-        _ => AbsoluteLength::Pixels(px(0.0)), // Default case for unrecognized units
+    #[test]
+    fn old_reverse_spellings() {
+        assert_eq!(
+            class_style("flex-col_reverse"),
+            class_style("flex-col-reverse")
+        );
+        assert_eq!(
+            class_style("flex-row_reverse"),
+            class_style("flex-row-reverse")
+        );
     }
 }
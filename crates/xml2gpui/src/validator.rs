@@ -0,0 +1,354 @@
+use gpui::*;
+use std::fmt;
+
+use crate::tree::{try_apply_class, try_apply_stateful_class, Component, STATE_MODIFIERS};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    // Renders, but probably not as intended (e.g. a typo in a class name)
+    Warning,
+    // The element can't be rendered
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    // Child indexes from the root, like in ComponentDiff
+    pub path: Vec<usize>,
+    pub elem: String,
+    pub attribute: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<{}> at {:?}, {}: {}",
+            self.elem, self.path, self.attribute, self.message
+        )
+    }
+}
+
+// Attributes an element can't be rendered without
+const REQUIRED_ATTRIBUTES: [(&str, &str); 5] = [
+    ("img", "src"),
+    ("svg", "path"),
+    ("input", "type"),
+    ("modal", "id"),
+    ("crumb", "label"),
+];
+
+// Prefixes of the classes that take a hex color, e.g. bg-[#0055ff]
+const HEX_COLOR_PREFIXES: [&str; 8] = [
+    "bg-[#",
+    "text-color-[#",
+    "border-[#",
+    "border-color-[#",
+    "shadow-color-[#",
+    "from-[#",
+    "via-[#",
+    "to-[#",
+];
+
+// Tailwind cursors GPUI has no CursorStyle for
+const UNSUPPORTED_CURSORS: [&str; 5] = [
+    "cursor-zoom-in",
+    "cursor-zoom-out",
+    "cursor-wait",
+    "cursor-progress",
+    "cursor-help",
+];
+
+pub struct ComponentTreeValidator;
+
+impl ComponentTreeValidator {
+    pub fn validate(root: &Component) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        validate_component(root, &mut Vec::new(), &mut errors);
+        errors
+    }
+}
+
+fn validate_component(
+    component: &Component,
+    path: &mut Vec<usize>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut report = |attribute: &str, severity: Severity, message: String| {
+        errors.push(ValidationError {
+            path: path.clone(),
+            elem: component.elem.clone(),
+            attribute: attribute.to_string(),
+            severity,
+            message,
+        })
+    };
+
+    for (elem, attribute) in REQUIRED_ATTRIBUTES {
+        if component.elem == elem && !component.attributes.iter().any(|(k, _)| k == attribute) {
+            report(
+                attribute,
+                Severity::Error,
+                format!("<{}> requires a {} attribute", elem, attribute),
+            );
+        }
+    }
+
+    // Only divs (and buttons, which are divs) get the stateful classes like gap-4 and hover:
+    let is_div = matches!(component.elem.as_str(), "div" | "button");
+    if let Some((_, classes)) = component.attributes.iter().find(|(k, _)| k == "class") {
+        for class_name in classes.split_whitespace() {
+            if let Some(message) = check_class(class_name, is_div) {
+                report("class", Severity::Warning, message);
+            }
+        }
+    }
+
+    for (index, child) in component.children.iter().enumerate() {
+        path.push(index);
+        validate_component(child, path, errors);
+        path.pop();
+    }
+}
+
+// None when the class is fine, otherwise what is wrong with it
+fn check_class(class_name: &str, is_div: bool) -> Option<String> {
+    if let Some((modifier, inner_class)) = class_name.split_once(':') {
+        // dark: works on any element, but not together with state modifiers
+        if modifier == "dark" {
+            return check_class(inner_class, false);
+        }
+        if !is_div {
+            return Some(format!(
+                "state modifier class \"{}\" only works on div and button",
+                class_name
+            ));
+        }
+        if !STATE_MODIFIERS.contains(&modifier) {
+            return Some(format!("unknown state modifier \"{}:\"", modifier));
+        }
+        // The inner class is applied to a plain style, so stateful classes don't work there
+        return check_class(inner_class, false);
+    }
+
+    // Malformed hex colors still apply (as transparent), so they are checked before trying the class
+    if let Some(value) = HEX_COLOR_PREFIXES
+        .iter()
+        .find_map(|prefix| class_name.strip_prefix(prefix))
+    {
+        let valid = value.strip_suffix(']').is_some_and(|hex| {
+            matches!(hex.len(), 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        });
+        if !valid {
+            return Some(format!("invalid hex color in \"{}\"", class_name));
+        }
+    }
+
+    if UNSUPPORTED_CURSORS.contains(&class_name) {
+        return Some(format!("GPUI has no cursor for \"{}\"", class_name));
+    }
+
+    if try_apply_class(StyleRefinement::default(), class_name).is_ok() {
+        return None;
+    }
+    if try_apply_stateful_class(div().id("validator"), class_name).is_ok() {
+        return if is_div {
+            None
+        } else {
+            Some(format!("\"{}\" only works on div and button", class_name))
+        };
+    }
+    Some(format!("unknown class \"{}\"", class_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::ComponentBuilder;
+
+    fn validate_class(elem: &str, classes: &str) -> Vec<ValidationError> {
+        ComponentTreeValidator::validate(
+            &ComponentBuilder::elem(elem).attr("class", classes).build(),
+        )
+    }
+
+    #[test]
+    fn known_classes_are_valid() {
+        assert_eq!(
+            validate_class(
+                "div",
+                "flex flex-col gap-4 hover:bg-[#0055ff] bg-[#0055ffcc]"
+            ),
+            []
+        );
+        assert_eq!(
+            validate_class("text", "text-sm dark:text-color-[#ffffff]"),
+            []
+        );
+    }
+
+    #[test]
+    fn unknown_class() {
+        let errors = validate_class("div", "flex flx-col");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].severity, Severity::Warning);
+        assert_eq!(errors[0].attribute, "class");
+        assert_eq!(errors[0].message, "unknown class \"flx-col\"");
+
+        let errors = validate_class("div", "hovr:bg-[#0055ff]");
+        assert_eq!(errors[0].message, "unknown state modifier \"hovr:\"");
+    }
+
+    #[test]
+    fn missing_required_attribute() {
+        let root = ComponentBuilder::elem("div")
+            .child(ComponentBuilder::elem("img").attr("class", "w-8").build())
+            .child(
+                ComponentBuilder::elem("img")
+                    .attr("src", "logo.svg")
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            ComponentTreeValidator::validate(&root),
+            [ValidationError {
+                path: vec![0],
+                elem: "img".to_string(),
+                attribute: "src".to_string(),
+                severity: Severity::Error,
+                message: "<img> requires a src attribute".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn malformed_hex_color() {
+        for class_name in [
+            "bg-[#0055f]",
+            "bg-[#0055ff",
+            "border-[#00gg00]",
+            "to-[#+12345]",
+        ] {
+            let errors = validate_class("div", class_name);
+            assert_eq!(errors.len(), 1, "{}", class_name);
+            assert_eq!(
+                errors[0].message,
+                format!("invalid hex color in \"{}\"", class_name)
+            );
+        }
+    }
+
+    #[test]
+    fn stateful_class_on_non_div() {
+        let errors = validate_class("text", "gap-4 hover:opacity-50");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "\"gap-4\" only works on div and button",
+                "state modifier class \"hover:opacity-50\" only works on div and button",
+            ]
+        );
+        assert_eq!(validate_class("button", "gap-4 hover:opacity-50"), []);
+    }
+
+    #[test]
+    fn malformed_grid_classes() {
+        assert_eq!(validate_class("div", "grid grid-cols-3 col-span-full"), []);
+        for class_name in ["grid-cols-foo", "grid-cols-13", "col-span-0"] {
+            let errors = validate_class("div", class_name);
+            assert_eq!(errors.len(), 1, "{}", class_name);
+            assert_eq!(
+                errors[0].message,
+                format!("unknown class \"{}\"", class_name)
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_transform_classes() {
+        assert_eq!(validate_class("div", "scale-150 -rotate-[15deg]"), []);
+        for class_name in ["scale-abc", "rotate-x", "translate-x-[50%]"] {
+            let errors = validate_class("div", class_name);
+            assert_eq!(errors.len(), 1, "{}", class_name);
+            assert_eq!(
+                errors[0].message,
+                format!("unknown class \"{}\"", class_name)
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_filter_classes() {
+        assert_eq!(validate_class("div", "blur-sm brightness-75 grayscale"), []);
+        for class_name in ["blur-xyz", "brightness-high", "contrast-[x]"] {
+            let errors = validate_class("div", class_name);
+            assert_eq!(errors.len(), 1, "{}", class_name);
+            assert_eq!(
+                errors[0].message,
+                format!("unknown class \"{}\"", class_name)
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_gradient_classes() {
+        assert_eq!(
+            validate_class("div", "bg-gradient-to-r from-[#0055ff] to-blue-500"),
+            []
+        );
+        for class_name in ["bg-gradient-to-up", "from-red", "to-bannana"] {
+            let errors = validate_class("div", class_name);
+            assert_eq!(errors.len(), 1, "{}", class_name);
+            assert_eq!(
+                errors[0].message,
+                format!("unknown class \"{}\"", class_name)
+            );
+        }
+    }
+
+    #[test]
+    fn border_colors() {
+        assert_eq!(
+            validate_class("div", "border border-color-[#ff000080] border-red-500"),
+            []
+        );
+        let errors = validate_class("div", "border-color-[#12]");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "invalid hex color in \"border-color-[#12]\""
+        );
+    }
+
+    #[test]
+    fn shadow_colors() {
+        assert_eq!(
+            validate_class("div", "shadow-lg shadow-color-[#0000aa80]"),
+            []
+        );
+        let errors = validate_class("div", "shadow-color-[#xyz]");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "invalid hex color in \"shadow-color-[#xyz]\""
+        );
+    }
+
+    #[test]
+    fn unsupported_cursors() {
+        assert_eq!(validate_class("div", "cursor-pointer cursor-grab"), []);
+        for class_name in UNSUPPORTED_CURSORS {
+            let errors = validate_class("div", class_name);
+            assert_eq!(errors.len(), 1, "{}", class_name);
+            assert_eq!(
+                errors[0].message,
+                format!("GPUI has no cursor for \"{}\"", class_name)
+            );
+        }
+    }
+}
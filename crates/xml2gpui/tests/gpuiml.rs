@@ -0,0 +1,73 @@
+use xml2gpui::gpuiml;
+use xml2gpui::tree::ComponentBuilder;
+
+#[test]
+fn empty_element() {
+    assert_eq!(gpuiml!(<div />), ComponentBuilder::elem("div").build());
+    assert_eq!(gpuiml!(<div></div>), ComponentBuilder::elem("div").build());
+}
+
+#[test]
+fn text() {
+    assert_eq!(
+        gpuiml!(<text>"Hello, world!"</text>),
+        ComponentBuilder::elem("text").text("Hello, world!").build()
+    );
+    assert_eq!(
+        gpuiml!(<text>Hello, world!</text>),
+        ComponentBuilder::elem("text").text("Hello, world!").build()
+    );
+}
+
+#[test]
+fn nested_children() {
+    assert_eq!(
+        gpuiml!(
+            <div class="flex flex-col">
+                <div class="flex">
+                    <text>"One"</text>
+                    <text>"Two"</text>
+                </div>
+                <img src="logo.svg" />
+            </div>
+        ),
+        ComponentBuilder::elem("div")
+            .attr("class", "flex flex-col")
+            .child(
+                ComponentBuilder::elem("div")
+                    .attr("class", "flex")
+                    .child(ComponentBuilder::elem("text").text("One").build())
+                    .child(ComponentBuilder::elem("text").text("Two").build())
+                    .build()
+            )
+            .child(
+                ComponentBuilder::elem("img")
+                    .attr("src", "logo.svg")
+                    .build()
+            )
+            .build()
+    );
+}
+
+#[test]
+fn attribute_with_special_characters() {
+    assert_eq!(
+        gpuiml!(<tag-input on:click="save" placeholder="<a & \"b\">" class="bg-[#0055ff]" />),
+        ComponentBuilder::elem("tag-input")
+            .attr("on:click", "save")
+            .attr("placeholder", "<a & \"b\">")
+            .attr("class", "bg-[#0055ff]")
+            .build()
+    );
+}
+
+#[test]
+fn string_literal() {
+    assert_eq!(
+        gpuiml!("<div class='flex'><text>a &lt; b</text></div>"),
+        ComponentBuilder::elem("div")
+            .attr("class", "flex")
+            .child(ComponentBuilder::elem("text").text("a < b").build())
+            .build()
+    );
+}
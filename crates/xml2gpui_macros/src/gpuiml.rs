@@ -0,0 +1,238 @@
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use quote::quote;
+use syn::{Error, LitStr, Result};
+
+// Element parsed at compile time, turned into ComponentBuilder calls
+struct Element {
+    elem: String,
+    text: Option<String>,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+}
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    // gpuiml!("<div>...</div>") works too, for XML that isn't valid Rust tokens
+    let xml = match syn::parse2::<LitStr>(input.clone()) {
+        Ok(literal) => literal.value(),
+        Err(_) => tokens_to_xml(input)?,
+    };
+    let root = parse(&xml)?;
+    Ok(to_builder(&root))
+}
+
+// Rust tokens lose the original spacing, so the XML is put back together: names inside tags
+// are joined with '-' and ':' (tag-input, on:click) and text tokens get single spaces
+fn tokens_to_xml(input: TokenStream) -> Result<String> {
+    let mut xml = String::new();
+    let mut in_tag = false;
+
+    for token in input {
+        match token {
+            TokenTree::Punct(punct) => match punct.as_char() {
+                '<' => {
+                    in_tag = true;
+                    xml.push('<');
+                }
+                '>' if in_tag => {
+                    in_tag = false;
+                    xml.push('>');
+                }
+                '/' | '=' | '-' | ':' if in_tag => xml.push(punct.as_char()),
+                c if !in_tag => {
+                    // Punctuation sticks to the previous word, like in "Hello, world!"
+                    xml.push_str(&escape(&c.to_string()));
+                }
+                c => {
+                    return Err(Error::new(
+                        punct.span(),
+                        format!("unexpected '{}' inside a tag", c),
+                    ))
+                }
+            },
+            TokenTree::Ident(ident) => {
+                push_word(&mut xml, in_tag);
+                xml.push_str(&ident.to_string());
+            }
+            TokenTree::Literal(literal) => {
+                let string = syn::parse2::<LitStr>(TokenTree::Literal(literal.clone()).into())
+                    .map(|literal| literal.value());
+                match (in_tag, string) {
+                    (true, Ok(value)) => {
+                        xml.push('"');
+                        xml.push_str(&escape(&value));
+                        xml.push('"');
+                    }
+                    (true, Err(_)) => {
+                        return Err(Error::new(
+                            literal.span(),
+                            "attribute values must be string literals",
+                        ))
+                    }
+                    // Numbers etc. are fine as text
+                    (false, string) => {
+                        push_word(&mut xml, in_tag);
+                        xml.push_str(&escape(&string.unwrap_or_else(|_| literal.to_string())));
+                    }
+                }
+            }
+            TokenTree::Group(group) if !in_tag => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                push_word(&mut xml, in_tag);
+                xml.push_str(open);
+                xml.push_str(&tokens_to_xml(group.stream())?);
+                xml.push_str(close);
+            }
+            TokenTree::Group(group) => {
+                return Err(Error::new(group.span(), "unexpected group inside a tag"))
+            }
+        }
+    }
+
+    Ok(xml)
+}
+
+// Adds the space that separates words, which the tokens don't have
+fn push_word(xml: &mut String, in_tag: bool) {
+    let joined = if in_tag {
+        // Tag and attribute names, e.g. <tag-input or on:click
+        xml.ends_with(['<', '/', '-', ':'])
+    } else {
+        // Start of the text right after a tag
+        xml.is_empty() || xml.ends_with(['>', '(', '[', '{'])
+    };
+    if !joined {
+        xml.push(' ');
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Same rules as xml2gpui::tree::parse_xml, minus <include> which needs a file system at runtime
+fn parse(xml: &str) -> Result<Element> {
+    let error = |message: String| Error::new(Span::call_site(), message);
+
+    let mut reader = Reader::from_str(xml);
+    reader
+        .expand_empty_elements(true)
+        .check_end_names(true)
+        .trim_text(true);
+
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) => break,
+            Ok(Event::Start(_)) if stack.is_empty() && root.is_some() => {
+                return Err(error("gpuiml! needs a single root element".to_string()));
+            }
+            Ok(Event::Start(e)) => {
+                let elem = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                let attributes = e
+                    .html_attributes()
+                    .map(|a| {
+                        let a = a.map_err(|e| error(format!("invalid attribute: {}", e)))?;
+                        let value = a
+                            .unescape_value()
+                            .map_err(|e| error(format!("invalid attribute: {}", e)))?;
+                        Ok((
+                            String::from_utf8_lossy(a.key.as_ref()).into_owned(),
+                            value.into_owned(),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                stack.push(Element {
+                    elem,
+                    text: None,
+                    attributes,
+                    children: Vec::new(),
+                });
+            }
+            Ok(Event::End(_)) => {
+                if let Some(finished) = stack.pop() {
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(finished),
+                        None => root = Some(finished),
+                    }
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e
+                    .unescape()
+                    .map_err(|e| error(format!("invalid text: {}", e)))?;
+                if let Some(parent) = stack.last_mut() {
+                    parent.text = Some(text.into_owned());
+                }
+            }
+            Ok(_) => (),
+            Err(e) => return Err(error(format!("invalid gpuiml: {}", e))),
+        }
+    }
+
+    if let Some(unclosed) = stack.last() {
+        return Err(error(format!("<{}> is not closed", unclosed.elem)));
+    }
+    root.ok_or_else(|| error("gpuiml! needs a root element".to_string()))
+}
+
+fn to_builder(element: &Element) -> TokenStream {
+    let elem = &element.elem;
+    let attributes = element
+        .attributes
+        .iter()
+        .map(|(key, value)| quote! { .attr(#key, #value) });
+    let text = element.text.iter().map(|text| quote! { .text(#text) });
+    let children = element.children.iter().map(|child| {
+        let child = to_builder(child);
+        quote! { .child(#child) }
+    });
+
+    quote! {
+        ::xml2gpui::tree::ComponentBuilder::elem(#elem)
+            #(#attributes)*
+            #(#text)*
+            #(#children)*
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(xml: &str) -> String {
+        parse(xml).err().expect("should not parse").to_string()
+    }
+
+    #[test]
+    fn single_root() {
+        let root = parse("<div><text>One</text><text>Two</text></div>").unwrap();
+        assert_eq!(root.elem, "div");
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(
+            parse_error("<div></div><div></div>"),
+            "gpuiml! needs a single root element"
+        );
+        assert_eq!(
+            parse_error("<div /><text>Two</text>"),
+            "gpuiml! needs a single root element"
+        );
+    }
+
+    #[test]
+    fn missing_root() {
+        assert_eq!(parse_error(""), "gpuiml! needs a root element");
+        assert_eq!(parse_error("<div><text>One</text>"), "<div> is not closed");
+    }
+}
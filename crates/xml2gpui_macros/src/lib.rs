@@ -1,5 +1,7 @@
 extern crate proc_macro;
 
+mod gpuiml;
+
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
@@ -10,11 +12,38 @@ use syn::{
     Expr, Ident, LitStr, Token,
 };
 
+// A class is either just its name, with the method name derived from it ("flex-col" calls
+// flex_col), or a ("class-name", method) pair for classes whose method is named differently.
+// The method can also take arguments or be a chain, e.g. ("grid", flex().flex_row())
+enum TailwindClass {
+    Derived(LitStr),
+    Method(LitStr, proc_macro2::TokenStream),
+}
+
+impl Parse for TailwindClass {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if !input.peek(syn::token::Paren) {
+            return Ok(TailwindClass::Derived(input.parse()?));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let class: LitStr = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let call: proc_macro2::TokenStream = content.parse()?;
+        // A bare method name is called without arguments
+        let call = match syn::parse2::<Ident>(call.clone()) {
+            Ok(method_name) => quote! { #method_name() },
+            Err(_) => call,
+        };
+        Ok(TailwindClass::Method(class, call))
+    }
+}
+
 // Updated to hold vectors of vectors to represent groups of tailwind classes
 struct TailwindToGpuiInput {
     element_name: Ident,
     class_name: Ident,
-    tailwind_class_groups: Vec<Vec<LitStr>>,
+    tailwind_class_groups: Vec<Vec<TailwindClass>>,
     default_case: Box<Expr>,
 }
 
@@ -31,7 +60,7 @@ impl Parse for TailwindToGpuiInput {
             let content;
             // Correctly parse the bracketed group
             syn::bracketed!(content in input);
-            let classes = Punctuated::<LitStr, Comma>::parse_terminated(&content)?;
+            let classes = Punctuated::<TailwindClass, Comma>::parse_terminated(&content)?;
 
             tailwind_class_groups.push(classes.into_iter().collect());
 
@@ -64,6 +93,15 @@ pub fn tailwind_to_gpui(input: TokenStream) -> TokenStream {
 
     let tailwind_matches = tailwind_class_groups.iter().flat_map(|group| {
         group.iter().map(|class| {
+            let class = match class {
+                TailwindClass::Derived(class) => class,
+                TailwindClass::Method(class, call) => {
+                    return quote! {
+                        #class => #element_name.#call,
+                    };
+                }
+            };
+
             // Replace "-" to "_" and "/" to "_" in class name
             let method_name = Ident::new(
                 class
@@ -109,3 +147,13 @@ pub fn tailwind_to_gpui(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+// Builds a xml2gpui::tree::Component from inline gpuiml at compile time, e.g.
+// let component = gpuiml!(<div class="flex"><text>"Hello"</text></div>);
+#[proc_macro]
+pub fn gpuiml(input: TokenStream) -> TokenStream {
+    match gpuiml::expand(input.into()) {
+        Ok(expanded) => expanded.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}